@@ -1,5 +1,7 @@
+mod paging;
 mod state;
 mod tools;
+mod watch;
 
 #[cfg(test)]
 mod tests;
@@ -15,5 +17,10 @@ Use set_working_directory to set the project directory first, then run cargo com
 fn main() -> Result<()> {
     let mut state = CargoTools::new()?;
 
-    mcplease::run::<tools::Tools, _>(&mut state, server_info!(), Some(INSTRUCTIONS))
+    // Pages are kept in memory, so they would be gone after a single call on the command line
+    if std::env::args().nth(1).as_deref() != Some("serve") {
+        state.output_pages_mut().disable();
+    }
+
+    mcplease::run::<paging::PagedTools, _>(&mut state, server_info!(), Some(INSTRUCTIONS))
 }
@@ -0,0 +1,247 @@
+use crate::{state::CargoTools, tools::Tools};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{AsToolsList, Tool},
+    types::ToolSchema,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::VecDeque;
+
+/// How many long results are kept for get_output_page
+const KEPT_OUTPUTS: usize = 16;
+
+/// Results of earlier tool calls that were too long to return at once, kept in memory
+#[derive(Debug)]
+pub struct OutputPages {
+    /// Page size in bytes, or 0 to return results whole
+    page_size: usize,
+    next_id: u64,
+    /// The most recent long results by id, oldest first
+    outputs: VecDeque<(u64, String)>,
+}
+
+impl OutputPages {
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page_size,
+            next_id: 1,
+            outputs: VecDeque::new(),
+        }
+    }
+
+    /// Return results whole from now on
+    pub fn disable(&mut self) {
+        self.page_size = 0;
+    }
+
+    /// Return the first page of a result, keeping the whole result for get_output_page if
+    /// it's longer than a page
+    pub fn first_page(&mut self, output: String) -> String {
+        if self.page_size == 0 || output.len() <= self.page_size {
+            return output;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let page = format_page(id, &output, 0, self.page_size);
+        self.outputs.push_back((id, output));
+        if self.outputs.len() > KEPT_OUTPUTS {
+            self.outputs.pop_front();
+        }
+        page
+    }
+
+    /// Return the page of an earlier result that starts at a continuation token
+    pub fn page(&self, token: &str) -> Result<String> {
+        let invalid = || anyhow!("Invalid continuation token '{token}'");
+        let (id, offset) = token.trim().split_once(':').ok_or_else(invalid)?;
+        let id: u64 = id.parse().map_err(|_| invalid())?;
+        let offset: usize = offset.parse().map_err(|_| invalid())?;
+
+        let (_, output) = self
+            .outputs
+            .iter()
+            .find(|(kept, _)| *kept == id)
+            .ok_or_else(|| {
+                anyhow!(
+                    "The output for token '{token}' is no longer available. Only the last \
+                     {KEPT_OUTPUTS} long results are kept while the server runs; run the \
+                     command again."
+                )
+            })?;
+        if offset >= output.len() || !output.is_char_boundary(offset) {
+            return Err(invalid());
+        }
+
+        Ok(format_page(id, output, offset, self.page_size))
+    }
+}
+
+/// A page of output, followed by the token for the next page if there is one
+fn format_page(id: u64, output: &str, offset: usize, page_size: usize) -> String {
+    let end = page_end(output, offset, page_size);
+    let mut page = output[offset..end].to_string();
+    if !page.ends_with('\n') {
+        page.push('\n');
+    }
+
+    if end < output.len() {
+        page.push_str(&format!(
+            "\n📄 Showing bytes {offset}-{end} of {} ({} KB more). Call get_output_page with \
+             token '{id}:{end}' for the next page.\n",
+            output.len(),
+            (output.len() - end).div_ceil(1024)
+        ));
+    } else {
+        page.push_str("\n📄 End of output\n");
+    }
+    page
+}
+
+/// Where the page starting at `offset` ends: after at most `page_size` bytes, at the end of
+/// a line if there is one
+fn page_end(output: &str, offset: usize, page_size: usize) -> usize {
+    let mut end = (offset + page_size.max(1)).min(output.len());
+    if end == output.len() {
+        return end;
+    }
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    match output[offset..end].rfind('\n') {
+        Some(newline) => offset + newline + 1,
+        // A single character longer than a page still makes progress
+        None if end == offset => offset + output[offset..].chars().next().map_or(1, char::len_utf8),
+        None => end,
+    }
+}
+
+// The tools, with results longer than a page cut into pages (see get_output_page). Not a doc
+// comment, which clap would show as the about text of the command line.
+#[derive(Debug, clap::Subcommand)]
+pub enum PagedTools {
+    #[command(flatten)]
+    Tools(Tools),
+}
+
+impl Serialize for PagedTools {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Self::Tools(tools) = self;
+        tools.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PagedTools {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Tools::deserialize(deserializer).map(Self::Tools)
+    }
+}
+
+impl AsToolsList for PagedTools {
+    fn tools_list() -> Vec<ToolSchema> {
+        Tools::tools_list()
+    }
+}
+
+impl Tool<CargoTools> for PagedTools {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let Self::Tools(tool) = self;
+        // Pages are no longer than a page already
+        if matches!(tool, Tools::GetOutputPage(_)) {
+            return tool.execute(state);
+        }
+
+        let output = tool.execute(state)?;
+        Ok(state.output_pages_mut().first_page(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ends_pages_at_lines() {
+        let output = "first line\nsecond line\nthird";
+        assert_eq!(page_end(output, 0, 16), 11);
+        assert_eq!(page_end(output, 11, 16), 23);
+        assert_eq!(page_end(output, 23, 16), output.len());
+        // A line longer than a page is cut in the middle
+        assert_eq!(page_end(output, 0, 4), 4);
+    }
+
+    #[test]
+    fn ends_pages_at_characters() {
+        // "ä" and "ö" are two bytes each
+        let output = "aäöb";
+        assert_eq!(page_end(output, 0, 2), 1);
+        assert_eq!(page_end(output, 1, 3), 3);
+        // A character wider than the page still makes progress
+        assert_eq!(page_end(output, 1, 1), 3);
+        assert_eq!(page_end("🦀", 0, 1), 4);
+        assert_eq!(page_end(output, 0, 0), 1);
+    }
+
+    #[test]
+    fn returns_short_results_whole() {
+        let mut pages = OutputPages::new(64);
+        assert_eq!(pages.first_page("short\n".into()), "short\n");
+
+        pages = OutputPages::new(4);
+        pages.disable();
+        assert_eq!(pages.first_page("not paged\n".into()), "not paged\n");
+        assert!(pages.outputs.is_empty());
+    }
+
+    #[test]
+    fn pages_through_results() {
+        let output: String = (0..30).map(|line| format!("line {line:02}\n")).collect();
+        let mut pages = OutputPages::new(64);
+
+        let mut page = pages.first_page(output.clone());
+        let mut joined = String::new();
+        loop {
+            let (text, footer) = page.rsplit_once("\n\n📄 ").unwrap();
+            joined.push_str(text);
+            joined.push('\n');
+            let Some((_, token)) = footer.split_once("token '") else {
+                assert_eq!(footer, "End of output\n");
+                break;
+            };
+            let token = token.split_once('\'').unwrap().0;
+            page = pages.page(token).unwrap();
+        }
+        assert_eq!(joined, output);
+    }
+
+    #[test]
+    fn rejects_bad_tokens() {
+        let mut pages = OutputPages::new(4);
+        pages.first_page("aäöb\n".into());
+
+        for token in ["", "1", "x:0", "1:x", "1:7", "1:2"] {
+            let error = pages.page(token).unwrap_err().to_string();
+            assert!(
+                error.starts_with("Invalid continuation token"),
+                "{token}: {error}"
+            );
+        }
+        assert!(pages.page(" 1:1 ").is_ok());
+        let error = pages.page("2:0").unwrap_err().to_string();
+        assert!(error.contains("no longer available"), "{error}");
+    }
+
+    #[test]
+    fn forgets_old_results() {
+        let mut pages = OutputPages::new(4);
+        for _ in 0..=KEPT_OUTPUTS {
+            pages.first_page("long output\n".into());
+        }
+
+        assert_eq!(pages.outputs.len(), KEPT_OUTPUTS);
+        assert!(pages.page("1:4").is_err());
+        assert!(pages.page("2:4").is_ok());
+        assert!(pages.page(&format!("{}:4", KEPT_OUTPUTS + 1)).is_ok());
+    }
+}
@@ -1,10 +1,16 @@
+use crate::{
+    paging::OutputPages,
+    tools::{cargo_options::GlobalOptions, diagnostics::Diagnostic},
+    watch::ProjectWatch,
+};
 use anyhow::{Result, anyhow};
 use fieldwork::Fieldwork;
 use mcplease::session::SessionStore;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fmt::{self, Debug, Formatter},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 /// Shared context data that can be used across multiple MCP servers
@@ -19,6 +25,25 @@ pub struct SharedContextData {
 pub struct CargoSessionData {
     /// Default toolchain to use for cargo commands (e.g., "stable", "nightly", "1.70.0")
     default_toolchain: Option<String>,
+    /// Default features to activate (e.g., "foo,bar")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_features: Option<String>,
+    /// Default profile to build with (e.g., "release")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_profile: Option<String>,
+    /// Environment variables set for every cargo command
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    default_env: BTreeMap<String, String>,
+    /// Default global cargo options, e.g. offline or locked
+    #[serde(default, skip_serializing_if = "is_default")]
+    default_cargo_options: GlobalOptions,
+    /// Tests that failed in the last cargo_test run, per project
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    failed_tests: BTreeMap<PathBuf, Vec<String>>,
+    /// Diagnostics of the last run of each compile command (e.g., "cargo check"), per
+    /// project
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    last_diagnostics: BTreeMap<PathBuf, BTreeMap<String, Vec<Diagnostic>>>,
 }
 
 /// Cargo tools with session support
@@ -31,6 +56,20 @@ pub struct CargoTools {
     shared_context_store: SessionStore<SharedContextData>,
     #[field(set, with)]
     default_session_id: &'static str,
+    /// Whether tools may publish to a registry (set with CARGO_MCP_ALLOW_PUBLISH)
+    allow_publish: bool,
+    /// Whether tools may change already published versions, e.g. yank them
+    /// (set with CARGO_MCP_ALLOW_REGISTRY_MUTATIONS)
+    allow_registry_mutations: bool,
+    /// Steps of cargo_release that may run for real, e.g. "publish" and "push"
+    /// (set with CARGO_MCP_RELEASE_ALLOWLIST as a comma-separated list)
+    release_allowlist: Vec<String>,
+    /// The file watch started with cargo_watch_start, if any (not persisted)
+    #[field(skip)]
+    watch: Option<ProjectWatch>,
+    /// Long results kept for get_output_page (not persisted). The page size is set with
+    /// CARGO_MCP_OUTPUT_PAGE_KB, and 0 turns paging off.
+    output_pages: OutputPages,
 }
 
 impl Debug for CargoTools {
@@ -39,6 +78,11 @@ impl Debug for CargoTools {
             .field("session_store", &self.session_store)
             .field("shared_context_store", &self.shared_context_store)
             .field("default_session_id", &self.default_session_id)
+            .field("allow_publish", &self.allow_publish)
+            .field("allow_registry_mutations", &self.allow_registry_mutations)
+            .field("release_allowlist", &self.release_allowlist)
+            .field("watch", &self.watch.as_ref().map(ProjectWatch::description))
+            .field("output_pages", &self.output_pages)
             .finish()
     }
 }
@@ -64,6 +108,11 @@ impl CargoTools {
             session_store,
             shared_context_store,
             default_session_id: "default",
+            allow_publish: env_flag("CARGO_MCP_ALLOW_PUBLISH"),
+            allow_registry_mutations: env_flag("CARGO_MCP_ALLOW_REGISTRY_MUTATIONS"),
+            release_allowlist: env_list("CARGO_MCP_RELEASE_ALLOWLIST"),
+            watch: None,
+            output_pages: OutputPages::new(env_kilobytes("CARGO_MCP_OUTPUT_PAGE_KB", 32) * 1024),
         };
 
         // Check for default toolchain from environment variable
@@ -124,6 +173,139 @@ impl CargoTools {
         })
     }
 
+    /// Get the default features for this session
+    pub fn get_default_features(&mut self, session_id: Option<&str>) -> Result<Option<String>> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data.default_features.clone())
+    }
+
+    /// Set the default features for this session
+    pub fn set_default_features(
+        &mut self,
+        features: Option<String>,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            data.default_features = features;
+        })
+    }
+
+    /// Get the default profile for this session
+    pub fn get_default_profile(&mut self, session_id: Option<&str>) -> Result<Option<String>> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data.default_profile.clone())
+    }
+
+    /// Set the default profile for this session
+    pub fn set_default_profile(
+        &mut self,
+        profile: Option<String>,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            data.default_profile = profile;
+        })
+    }
+
+    /// Get the environment variables set for every cargo command in this session
+    pub fn get_default_env(
+        &mut self,
+        session_id: Option<&str>,
+    ) -> Result<BTreeMap<String, String>> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data.default_env.clone())
+    }
+
+    /// Set the environment variables for every cargo command in this session
+    pub fn set_default_env(
+        &mut self,
+        env: BTreeMap<String, String>,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            data.default_env = env;
+        })
+    }
+
+    /// Get the default global cargo options for this session
+    pub fn get_default_cargo_options(&mut self, session_id: Option<&str>) -> Result<GlobalOptions> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data.default_cargo_options.clone())
+    }
+
+    /// Set the default global cargo options for this session
+    pub fn set_default_cargo_options(
+        &mut self,
+        options: GlobalOptions,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            data.default_cargo_options = options;
+        })
+    }
+
+    /// Get the tests that failed in the last cargo_test run of a project
+    pub fn get_failed_tests(
+        &mut self,
+        project_path: &Path,
+        session_id: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data
+            .failed_tests
+            .get(project_path)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Record the tests that failed in a cargo_test run of a project
+    pub fn set_failed_tests(
+        &mut self,
+        project_path: PathBuf,
+        tests: Vec<String>,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            if tests.is_empty() {
+                data.failed_tests.remove(&project_path);
+            } else {
+                data.failed_tests.insert(project_path, tests);
+            }
+        })
+    }
+
+    /// Get the diagnostics of the last run of a compile command (e.g., "cargo check") in a
+    /// project, if it ran before
+    pub fn get_last_diagnostics(
+        &mut self,
+        project_path: &Path,
+        command: &str,
+        session_id: Option<&str>,
+    ) -> Result<Option<Vec<Diagnostic>>> {
+        let session_data = self.get_cargo_session(session_id)?;
+        Ok(session_data
+            .last_diagnostics
+            .get(project_path)
+            .and_then(|commands| commands.get(command))
+            .cloned())
+    }
+
+    /// Record the diagnostics of a run of a compile command in a project
+    pub fn set_last_diagnostics(
+        &mut self,
+        project_path: PathBuf,
+        command: &str,
+        diagnostics: Vec<Diagnostic>,
+        session_id: Option<&str>,
+    ) -> Result<()> {
+        self.update_cargo_session(session_id, |data| {
+            data.last_diagnostics
+                .entry(project_path)
+                .or_default()
+                .insert(command.to_string(), diagnostics);
+        })
+    }
+
     /// Check if the current working directory is a Rust project
     pub fn ensure_rust_project(&mut self, session_id: Option<&str>) -> Result<PathBuf> {
         let context = self
@@ -140,4 +322,41 @@ impl CargoTools {
 
         Ok(context)
     }
+
+    /// The active file watch, if any
+    pub fn watch(&self) -> Option<&ProjectWatch> {
+        self.watch.as_ref()
+    }
+
+    /// Replace the active file watch, returning the previous one. Dropping a watch stops it.
+    pub fn replace_watch(&mut self, watch: Option<ProjectWatch>) -> Option<ProjectWatch> {
+        std::mem::replace(&mut self.watch, watch)
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Read a boolean server setting from the environment
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| matches!(value.as_str(), "1" | "true" | "yes"))
+}
+
+/// Read a size server setting in KB from the environment
+fn env_kilobytes(name: &str, default: usize) -> usize {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+/// Read a comma-separated list server setting from the environment
+fn env_list(name: &str) -> Vec<String> {
+    std::env::var(name)
+        .unwrap_or_default()
+        .split(',')
+        .map(|item| item.trim().to_lowercase())
+        .filter(|item| !item.is_empty())
+        .collect()
 }
@@ -1,17 +1,114 @@
 use crate::state::CargoTools;
+mod bench_results;
+pub(crate) mod cargo_options;
 mod cargo_utils;
+mod crates_io;
+pub(crate) mod diagnostics;
+mod test_results;
 mcplease::tools!(
     CargoTools,
     (CargoCheck, cargo_check, "cargo_check"),
     (CargoClippy, cargo_clippy, "cargo_clippy"),
     (CargoTest, cargo_test, "cargo_test"),
     (CargoFmtCheck, cargo_fmt_check, "cargo_fmt_check"),
+    (CargoFmt, cargo_fmt, "cargo_fmt"),
     (CargoBuild, cargo_build, "cargo_build"),
     (CargoBench, cargo_bench, "cargo_bench"),
+    (CargoDoc, cargo_doc, "cargo_doc"),
     (CargoAdd, cargo_add, "cargo_add"),
     (CargoRemove, cargo_remove, "cargo_remove"),
     (CargoUpdate, cargo_update, "cargo_update"),
     (CargoClean, cargo_clean, "cargo_clean"),
+    (CargoTree, cargo_tree, "cargo_tree"),
+    (CargoMetadata, cargo_metadata, "cargo_metadata"),
+    (CargoPublish, cargo_publish, "cargo_publish"),
+    (CargoPackage, cargo_package, "cargo_package"),
+    (CargoInstall, cargo_install, "cargo_install"),
+    (CargoNew, cargo_new, "cargo_new"),
+    (CargoInit, cargo_init, "cargo_init"),
+    (CargoFix, cargo_fix, "cargo_fix"),
+    (CargoExpand, cargo_expand, "cargo_expand"),
+    (CargoAudit, cargo_audit, "cargo_audit"),
+    (CargoOutdated, cargo_outdated, "cargo_outdated"),
+    (CargoVendor, cargo_vendor, "cargo_vendor"),
+    (CargoMiri, cargo_miri, "cargo_miri"),
+    (CargoFuzz, cargo_fuzz, "cargo_fuzz"),
+    (CargoCoverage, cargo_coverage, "cargo_coverage"),
+    (CargoNextest, cargo_nextest, "cargo_nextest"),
+    (CargoDeny, cargo_deny, "cargo_deny"),
+    (CargoUdeps, cargo_udeps, "cargo_udeps"),
+    (
+        CargoSemverChecks,
+        cargo_semver_checks,
+        "cargo_semver_checks"
+    ),
+    (CargoMsrv, cargo_msrv, "cargo_msrv"),
+    (CargoBloat, cargo_bloat, "cargo_bloat"),
+    (CargoAsm, cargo_asm, "cargo_asm"),
+    (CargoGeiger, cargo_geiger, "cargo_geiger"),
+    (CargoHack, cargo_hack, "cargo_hack"),
+    (CargoMutants, cargo_mutants, "cargo_mutants"),
+    (CargoInsta, cargo_insta, "cargo_insta"),
+    (CargoMachete, cargo_machete, "cargo_machete"),
+    (CargoYank, cargo_yank, "cargo_yank"),
+    (CratesSearch, crates_search, "crates_search"),
+    (CrateInfo, crate_info, "crate_info"),
+    (
+        CargoLocateProject,
+        cargo_locate_project,
+        "cargo_locate_project"
+    ),
+    (CargoRustc, cargo_rustc, "cargo_rustc"),
+    (CargoRustdoc, cargo_rustdoc, "cargo_rustdoc"),
+    (CargoGenerate, cargo_generate, "cargo_generate"),
+    (CargoWatchStart, cargo_watch_start, "cargo_watch_start"),
+    (CargoWatchStatus, cargo_watch_status, "cargo_watch_status"),
+    (CargoWatchStop, cargo_watch_stop, "cargo_watch_stop"),
+    (CargoCross, cargo_cross, "cargo_cross"),
+    (CargoZigbuild, cargo_zigbuild, "cargo_zigbuild"),
+    (CargoTarpaulin, cargo_tarpaulin, "cargo_tarpaulin"),
+    (CargoCareful, cargo_careful, "cargo_careful"),
+    (CargoPgo, cargo_pgo, "cargo_pgo"),
+    (CargoDist, cargo_dist, "cargo_dist"),
+    (CargoRelease, cargo_release, "cargo_release"),
+    (CargoSetVersion, cargo_set_version, "cargo_set_version"),
+    (CargoSort, cargo_sort, "cargo_sort"),
+    (CargoFlamegraph, cargo_flamegraph, "cargo_flamegraph"),
+    (CargoBinstall, cargo_binstall, "cargo_binstall"),
+    (
+        RustupToolchainList,
+        rustup_toolchain_list,
+        "rustup_toolchain_list"
+    ),
+    (
+        RustupToolchainInstall,
+        rustup_toolchain_install,
+        "rustup_toolchain_install"
+    ),
+    (RustupDefault, rustup_default, "rustup_default"),
+    (
+        RustupActiveToolchain,
+        rustup_active_toolchain,
+        "rustup_active_toolchain"
+    ),
+    (RustupComponent, rustup_component, "rustup_component"),
+    (RustupTarget, rustup_target, "rustup_target"),
+    (CargoPkgid, cargo_pkgid, "cargo_pkgid"),
+    (RustcExplain, rustc_explain, "rustc_explain"),
+    (CargoTestList, cargo_test_list, "cargo_test_list"),
+    (SetCargoDefaults, set_cargo_defaults, "set_cargo_defaults"),
+    (
+        SetDefaultFeatures,
+        set_default_features,
+        "set_default_features"
+    ),
+    (SetDefaultEnv, set_default_env, "set_default_env"),
+    (
+        SetDefaultProfile,
+        set_default_profile,
+        "set_default_profile"
+    ),
+    (GetOutputPage, get_output_page, "get_output_page"),
     (
         SetWorkingDirectory,
         set_working_directory,
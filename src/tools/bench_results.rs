@@ -0,0 +1,242 @@
+use serde::Serialize;
+
+/// A confidence interval as criterion prints it: lower bound, point estimate, upper bound
+#[derive(Debug, Clone, Serialize)]
+pub struct Interval {
+    pub lower: f64,
+    pub estimate: f64,
+    pub upper: f64,
+    /// Time unit (e.g., "ns", "µs", "ms") or "%" for changes
+    pub unit: String,
+}
+
+/// One benchmark of a criterion run
+#[derive(Debug, Default, Serialize)]
+pub struct BenchResult {
+    pub name: String,
+    /// Time per iteration: criterion's estimate with its confidence interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Interval>,
+    /// Change of the time against the baseline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change: Option<Interval>,
+    /// Criterion's verdict on the change, e.g. "Performance has regressed."
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+}
+
+impl BenchResult {
+    /// Whether criterion considers the change a statistically significant slowdown
+    pub fn regressed(&self) -> bool {
+        self.verdict
+            .as_deref()
+            .is_some_and(|verdict| verdict.contains("regressed"))
+    }
+
+    /// Whether criterion considers the change a statistically significant speedup
+    pub fn improved(&self) -> bool {
+        self.verdict
+            .as_deref()
+            .is_some_and(|verdict| verdict.contains("improved"))
+    }
+}
+
+/// Parse criterion's human-readable output, which looks like
+///
+/// ```text
+/// parse/large             time:   [1.2345 ms 1.2400 ms 1.2456 ms]
+///                         change: [+2.1234% +3.0000% +3.9000%] (p = 0.00 < 0.05)
+///                         Performance has regressed.
+/// ```
+///
+/// Names too long for the column are printed on a line of their own before `time:`.
+pub fn parse_criterion_output(stdout: &str) -> Vec<BenchResult> {
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut previous_line = "";
+
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        if let Some((name, interval)) = line.split_once("time:")
+            && interval.contains('[')
+        {
+            match (name.trim(), results.last_mut()) {
+                // With throughput, changes are a "change:" line followed by time and thrpt
+                ("", Some(result)) if previous_line.trim() == "change:" => {
+                    result.change = parse_interval(interval);
+                }
+                (name, _) => {
+                    let name = if name.is_empty() {
+                        previous_line.trim()
+                    } else {
+                        name
+                    };
+                    results.push(BenchResult {
+                        name: name.to_string(),
+                        time: parse_interval(interval),
+                        ..BenchResult::default()
+                    });
+                }
+            }
+        } else if let Some(result) = results.last_mut() {
+            if let Some(change) = trimmed.strip_prefix("change:")
+                && result.change.is_none()
+            {
+                result.change = parse_interval(change);
+            } else if result.change.is_some()
+                && result.verdict.is_none()
+                && (trimmed.starts_with("Performance has")
+                    || trimmed.starts_with("No change in performance")
+                    || trimmed.starts_with("Change within noise"))
+            {
+                result.verdict = Some(trimmed.to_string());
+            }
+        }
+        previous_line = line;
+    }
+
+    results
+}
+
+/// Parse "[26.029 µs 26.251 µs 26.505 µs]" or "[-3.0987% -1.7502% -0.3871%]"
+fn parse_interval(text: &str) -> Option<Interval> {
+    let inside = text.split_once('[')?.1.split_once(']')?.0;
+    let tokens: Vec<&str> = inside.split_whitespace().collect();
+
+    let (values, unit): (Vec<&str>, &str) = match tokens.as_slice() {
+        [a, unit, b, _, c, _] => (vec![a, b, c], unit),
+        [a, b, c] => (
+            vec![
+                a.trim_end_matches('%'),
+                b.trim_end_matches('%'),
+                c.trim_end_matches('%'),
+            ],
+            "%",
+        ),
+        _ => return None,
+    };
+
+    let values: Vec<f64> = values
+        .iter()
+        .map(|value| value.trim_start_matches('+').parse())
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    Some(Interval {
+        lower: values[0],
+        estimate: values[1],
+        upper: values[2],
+        unit: unit.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Output of `cargo bench` with criterion, against a saved baseline
+    const OUTPUT: &str = "\
+Benchmarking fib 20
+Benchmarking fib 20: Warming up for 3.0000 s
+Benchmarking fib 20: Collecting 100 samples in estimated 5.0427 s (192k iterations)
+Benchmarking fib 20: Analyzing
+fib 20                  time:   [26.029 µs 26.251 µs 26.505 µs]
+                        change: [-3.0987% -1.7502% -0.3871%] (p = 0.01 < 0.05)
+                        Performance has improved.
+Found 11 outliers among 100 measurements (11.00%)
+  6 (6.00%) high mild
+  5 (5.00%) high severe
+
+parse/a_rather_long_benchmark_name
+                        time:   [1.2345 ms 1.2400 ms 1.2456 ms]
+                        change: [+2.1234% +3.0000% +3.9000%] (p = 0.00 < 0.05)
+                        Performance has regressed.
+
+noop                    time:   [312.45 ps 313.01 ps 313.70 ps]
+                        change: [-0.5012% +0.1020% +0.7234%] (p = 0.74 > 0.05)
+                        No change in performance detected.
+
+sleep                   time:   [1.0012 s 1.0015 s 1.0019 s]
+                        change: [-0.2090% +0.0144% +0.2411%] (p = 0.03 < 0.05)
+                        Change within noise threshold.
+
+hash/1024               time:   [812.95 ns 815.27 ns 817.86 ns]
+                        thrpt:  [1.1661 GiB/s 1.1698 GiB/s 1.1731 GiB/s]
+                 change:
+                        time:   [+6.2287% +6.8146% +7.4089%] (p = 0.00 < 0.05)
+                        thrpt:  [-6.8978% -6.3798% -5.8635%]
+                        Performance has regressed.
+
+new_bench               time:   [10.512 ns 10.601 ns 10.713 ns]
+";
+
+    fn result<'a>(results: &'a [BenchResult], name: &str) -> &'a BenchResult {
+        results.iter().find(|r| r.name == name).unwrap()
+    }
+
+    #[test]
+    fn parses_time_units() {
+        let results = parse_criterion_output(OUTPUT);
+        let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "fib 20",
+                "parse/a_rather_long_benchmark_name",
+                "noop",
+                "sleep",
+                "hash/1024",
+                "new_bench"
+            ]
+        );
+
+        let units: Vec<_> = results
+            .iter()
+            .map(|r| r.time.as_ref().unwrap().unit.as_str())
+            .collect();
+        assert_eq!(units, ["µs", "ms", "ps", "s", "ns", "ns"]);
+
+        let time = result(&results, "fib 20").time.as_ref().unwrap();
+        assert_eq!(
+            (time.lower, time.estimate, time.upper),
+            (26.029, 26.251, 26.505)
+        );
+    }
+
+    #[test]
+    fn parses_changes() {
+        let results = parse_criterion_output(OUTPUT);
+
+        let change = result(&results, "fib 20").change.as_ref().unwrap();
+        assert_eq!(change.unit, "%");
+        assert_eq!(
+            (change.lower, change.estimate, change.upper),
+            (-3.0987, -1.7502, -0.3871)
+        );
+
+        // The time change, not the throughput change
+        let change = result(&results, "hash/1024").change.as_ref().unwrap();
+        assert_eq!(change.estimate, 6.8146);
+
+        assert!(result(&results, "new_bench").change.is_none());
+    }
+
+    #[test]
+    fn classifies_changes() {
+        let results = parse_criterion_output(OUTPUT);
+        let verdict = |name| {
+            let result = result(&results, name);
+            (result.improved(), result.regressed())
+        };
+
+        assert_eq!(verdict("fib 20"), (true, false));
+        assert_eq!(verdict("parse/a_rather_long_benchmark_name"), (false, true));
+        assert_eq!(verdict("noop"), (false, false));
+        assert_eq!(verdict("sleep"), (false, false));
+        assert_eq!(verdict("hash/1024"), (false, true));
+        assert_eq!(verdict("new_bench"), (false, false));
+        assert_eq!(
+            result(&results, "noop").verdict.as_deref(),
+            Some("No change in performance detected.")
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, format_cargo_output, run_cargo_command, workspace_root,
+};
 use anyhow::{Result, anyhow};
 use mcplease::{
     traits::{Tool, WithExamples},
@@ -9,10 +12,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Add dependencies to Cargo.toml using cargo add
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_add")]
 pub struct CargoAdd {
-    /// List of dependencies to add (e.g., ['serde', 'tokio@1.0'])
+    /// List of dependencies to add, optionally with a version requirement
+    /// (e.g., ['serde', 'tokio@1.0'])
     pub dependencies: Vec<String>,
 
     /// Optional package name (for workspaces)
@@ -25,6 +29,11 @@ pub struct CargoAdd {
     #[arg(long)]
     pub dev: Option<bool>,
 
+    /// Add as build dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub build: Option<bool>,
+
     /// Add as optional dependencies
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -35,6 +44,15 @@ pub struct CargoAdd {
     #[arg(long)]
     pub features: Option<Vec<String>>,
 
+    /// Optional name to rename the dependency to (only valid with a single dependency)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rename: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -53,48 +71,46 @@ impl WithExamples for CargoAdd {
                 description: "Add a simple dependency",
                 item: Self {
                     dependencies: vec!["serde".into()],
-                    package: None,
-                    dev: None,
-                    optional: None,
-                    features: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Add multiple dependencies with versions",
                 item: Self {
                     dependencies: vec!["serde@1.0".into(), "tokio@1.0".into()],
-                    package: None,
-                    dev: None,
-                    optional: None,
-                    features: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Add a dev dependency",
                 item: Self {
                     dependencies: vec!["criterion".into()],
-                    package: None,
                     dev: Some(true),
-                    optional: None,
-                    features: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Add dependency with features",
                 item: Self {
                     dependencies: vec!["tokio".into()],
-                    package: None,
-                    dev: None,
-                    optional: None,
                     features: Some(vec!["full".into()]),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Add a build dependency",
+                item: Self {
+                    dependencies: vec!["cc".into()],
+                    build: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Add a dependency under a different name",
+                item: Self {
+                    dependencies: vec!["serde_json@1".into()],
+                    rename: Some("json".into()),
+                    ..Self::default()
                 },
             },
         ]
@@ -107,13 +123,19 @@ impl Tool<CargoTools> for CargoAdd {
             return Err(anyhow!("No dependencies specified"));
         }
 
-        let project_path = state.ensure_rust_project(None)?;
+        if self.rename.is_some() && self.dependencies.len() > 1 {
+            return Err(anyhow!("rename can only be used with a single dependency"));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
 
         // Use toolchain from args, session default, or none
         let toolchain = self
             .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
 
         let mut args = vec!["add"];
 
@@ -125,6 +147,10 @@ impl Tool<CargoTools> for CargoAdd {
             args.push("--dev");
         }
 
+        if self.build.unwrap_or(false) {
+            args.push("--build");
+        }
+
         if self.optional.unwrap_or(false) {
             args.push("--optional");
         }
@@ -132,17 +158,90 @@ impl Tool<CargoTools> for CargoAdd {
         let features_str;
 
         if let Some(ref features) = self.features
-            && !features.is_empty() {
-                features_str = features.join(",");
-                args.extend_from_slice(&["--features", &features_str]);
-            }
+            && !features.is_empty()
+        {
+            features_str = features.join(",");
+            args.extend_from_slice(&["--features", &features_str]);
+        }
+
+        if let Some(ref rename) = self.rename {
+            args.extend_from_slice(&["--rename", rename]);
+        }
 
         // Add the dependencies
         for dep in &self.dependencies {
             args.push(dep);
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo add")
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo add");
+
+        // Members of a workspace share the lockfile at its root
+        let lockfile_dir = workspace_root(&project_path).unwrap_or_else(|| project_path.clone());
+        let lockfile = std::fs::read_to_string(lockfile_dir.join("Cargo.lock")).unwrap_or_default();
+        let added = added_dependencies(&output.stderr, &lockfile);
+        if !added.is_empty() {
+            result.push_str("📦 Added dependencies:\n");
+            for line in added {
+                result.push_str(&format!("  • {line}\n"));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Summarize what cargo add wrote to the manifest, along with the version
+/// that was locked for each dependency.
+///
+/// Cargo reports manifest changes as `Adding serde v1.0 to dependencies` and
+/// lockfile changes as `Adding serde v1.0.219`. When the lockfile is created
+/// from scratch cargo doesn't list the locked packages, so fall back to
+/// reading them from Cargo.lock.
+fn added_dependencies<'a>(stderr: &'a str, lockfile: &'a str) -> Vec<String> {
+    let mut manifest_entries = vec![];
+    let mut locked = HashMap::new();
+
+    let mut lockfile_name = None;
+    for line in lockfile.lines() {
+        if let Some(name) = line.strip_prefix("name = ") {
+            lockfile_name = Some(name.trim_matches('"'));
+        } else if let Some(version) = line.strip_prefix("version = ")
+            && let Some(name) = lockfile_name.take()
+        {
+            locked.insert(name, format!("v{}", version.trim_matches('"')));
+        }
+    }
+
+    for line in stderr.lines() {
+        let Some(rest) = line.trim().strip_prefix("Adding ") else {
+            continue;
+        };
+
+        match rest.split_once(" to ") {
+            Some((dependency, section)) => manifest_entries.push((dependency, section)),
+            None => {
+                if let Some((name, version)) = rest.split_once(' ') {
+                    locked.insert(name, version.to_string());
+                }
+            }
+        }
     }
+
+    manifest_entries
+        .into_iter()
+        .map(|(dependency, section)| {
+            let name = dependency.split(' ').next().unwrap_or(dependency);
+            match locked.get(name) {
+                Some(version) => format!("{dependency} to {section} (resolved to {version})"),
+                None => format!("{dependency} to {section}"),
+            }
+        })
+        .collect()
 }
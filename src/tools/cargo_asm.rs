@@ -0,0 +1,188 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which representation of the generated code to show
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AsmOutput {
+    #[default]
+    Asm,
+    Llvm,
+    Mir,
+}
+
+/// Show the generated assembly, LLVM IR or MIR for a function with cargo asm
+/// (requires cargo-show-asm)
+///
+/// Without a function, lists the functions that can be shown.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_asm")]
+pub struct CargoAsm {
+    /// Optional path of the function to show (e.g., 'my_crate::parse'). If several
+    /// functions match, they are listed instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub function: Option<String>,
+
+    /// Optional index to pick one of several matching functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub index: Option<u32>,
+
+    /// Whether to show 'asm' (the default), 'llvm' IR or 'mir'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub output: Option<AsmOutput>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional binary target to inspect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Build in release mode (defaults to true, since that's the code that ships)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Optional optimization level overriding the profile (e.g., '0', '1', '2', '3', 's', 'z')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub opt_level: Option<String>,
+
+    /// Interleave the Rust source with the generated code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rust: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoAsm {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List the functions that can be inspected",
+                item: Self::default(),
+            },
+            Example {
+                description: "Show the optimized assembly for a function",
+                item: Self {
+                    function: Some("my_crate::checksum".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the LLVM IR for a function built with opt-level 1",
+                item: Self {
+                    function: Some("my_crate::checksum".into()),
+                    output: Some(AsmOutput::Llvm),
+                    opt_level: Some("1".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoAsm {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("asm", "cargo-show-asm")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let release = self.release.unwrap_or(true);
+        let mut args = vec!["asm", "--simplify"];
+
+        args.push(match self.output.unwrap_or_default() {
+            AsmOutput::Asm => "--asm",
+            AsmOutput::Llvm => "--llvm",
+            AsmOutput::Mir => "--mir",
+        });
+
+        if !release {
+            args.push("--dev");
+        }
+
+        if self.rust.unwrap_or(false) {
+            args.push("--rust");
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if let Some(ref function) = self.function {
+            args.push(function);
+        }
+
+        let index_str;
+        if let Some(index) = self.index {
+            index_str = index.to_string();
+            args.push(&index_str);
+        }
+
+        // cargo asm has no opt-level flag, so override the profile through the environment
+        let mut env = self.cargo_env.unwrap_or_default();
+        if let Some(opt_level) = self.opt_level {
+            let profile = if release { "RELEASE" } else { "DEV" };
+            env.insert(format!("CARGO_PROFILE_{profile}_OPT_LEVEL"), opt_level);
+        }
+
+        let cmd = create_cargo_command(&args, toolchain.as_deref(), Some(&env), &cargo_options);
+        execute_cargo_command(cmd, &project_path, "cargo asm")
+    }
+}
@@ -0,0 +1,178 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Check Cargo.lock against the RustSec advisory database with cargo audit
+/// (requires cargo-audit)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_audit")]
+pub struct CargoAudit {
+    /// Advisory IDs to ignore (e.g., ['RUSTSEC-2020-0071'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub ignore: Option<Vec<String>>,
+
+    /// Return the findings as structured JSON instead of the human-readable report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub json: Option<bool>,
+
+    /// Treat warnings (unmaintained, yanked, unsound crates) as errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub deny_warnings: Option<bool>,
+
+    /// Don't fetch the latest advisory database, use the local copy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_fetch: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoAudit {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Audit dependencies for known vulnerabilities",
+                item: Self::default(),
+            },
+            Example {
+                description: "Get the findings as structured JSON",
+                item: Self {
+                    json: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Audit while ignoring an accepted advisory",
+                item: Self {
+                    ignore: Some(vec!["RUSTSEC-2020-0071".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Fail on warnings using the local advisory database",
+                item: Self {
+                    deny_warnings: Some(true),
+                    no_fetch: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoAudit {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("audit", "cargo-audit")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let json = self.json.unwrap_or(false);
+
+        let mut args = vec!["audit"];
+
+        if json {
+            args.push("--json");
+        }
+
+        for id in self.ignore.iter().flatten() {
+            args.extend_from_slice(&["--ignore", id]);
+        }
+
+        if self.deny_warnings.unwrap_or(false) {
+            args.extend_from_slice(&["--deny", "warnings"]);
+        }
+
+        if self.no_fetch.unwrap_or(false) {
+            args.push("--no-fetch");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        // cargo audit exits non-zero when vulnerabilities are found, but the report is still valid
+        if json && let Ok(report) = serde_json::from_str::<Value>(&output.stdout) {
+            output.stdout = serde_json::to_string_pretty(&summarize_report(&report))?;
+        }
+
+        Ok(format_cargo_output(&output, &project_path, "cargo audit"))
+    }
+}
+
+/// Reduce a cargo audit JSON report to the fields needed to act on each finding
+fn summarize_report(report: &Value) -> Value {
+    let finding = |entry: &Value| {
+        let advisory = &entry["advisory"];
+        json!({
+            "id": advisory["id"],
+            "package": entry["package"]["name"],
+            "version": entry["package"]["version"],
+            "title": advisory["title"],
+            "url": advisory["url"],
+            "patched_versions": entry["versions"]["patched"],
+        })
+    };
+
+    let vulnerabilities: Vec<Value> = report["vulnerabilities"]["list"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(finding)
+        .collect();
+
+    let warnings: Vec<Value> = report["warnings"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(kind, entries)| {
+            entries.as_array().into_iter().flatten().map(move |entry| {
+                let mut warning = finding(entry);
+                warning["kind"] = Value::String(kind.clone());
+                warning
+            })
+        })
+        .collect();
+
+    json!({
+        "vulnerability_count": vulnerabilities.len(),
+        "vulnerabilities": vulnerabilities,
+        "warning_count": warnings.len(),
+        "warnings": warnings,
+    })
+}
@@ -1,15 +1,27 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
-use anyhow::Result;
+use crate::tools::bench_results::{BenchResult, parse_criterion_output};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, format_cargo_output, run_cargo_command, run_cargo_command_with_timeout,
+};
+use anyhow::{Result, anyhow};
 use mcplease::{
     traits::{Tool, WithExamples},
     types::Example,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 /// Run cargo bench to execute benchmarks
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+///
+/// For criterion benchmarks, the output is followed by a JSON list of each benchmark's time
+/// and its change against the baseline, with confidence intervals.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_bench")]
 pub struct CargoBench {
     /// Optional package name to benchmark (for workspaces)
@@ -17,22 +29,94 @@ pub struct CargoBench {
     #[arg(long)]
     pub package: Option<String>,
 
-    /// Optional specific benchmark name to run
+    /// Optional bench target to run, i.e. one file in benches/ (e.g., 'parser' for
+    /// benches/parser.rs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bench_target: Option<String>,
+
+    /// Optional filter; only benchmarks whose name contains it are run
+    #[serde(alias = "bench_name", skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Run the benchmarks of all workspace packages that have bench targets, one package
+    /// after the other, with the results grouped by package
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional packages to skip when benchmarking the workspace (implies workspace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude: Option<Vec<String>>,
+
+    /// Optional custom profile to build the benchmarks with (defaults to the bench profile)
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
-    pub bench_name: Option<String>,
+    pub profile: Option<String>,
 
-    /// Optional baseline name for comparison
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Optional name to save the results under as a criterion baseline, to compare against
+    /// later with compare_baseline
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
     pub baseline: Option<String>,
 
+    /// Optional criterion baseline to compare the results against (saved earlier with
+    /// baseline); the response lists the change of each benchmark
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub compare_baseline: Option<String>,
+
+    /// Optional regression limit in percent. If any benchmark got slower than this compared
+    /// to the baseline (by criterion's estimate of the change), the call fails with a list
+    /// of the regressed benchmarks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub max_regression_percent: Option<u32>,
+
+    /// Only compile the benchmarks without running them, to quickly check they still build
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_run: Option<bool>,
+
     /// Display one character per benchmark instead of one line.
     /// Produces compact output with result summary.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
     pub quiet: Option<bool>,
 
+    /// Optional time limit in seconds, including the build. The command and any processes it
+    /// started are stopped when it runs out, and the output up to that point is returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub timeout_secs: Option<u64>,
+
+    /// Optional number of parallel build jobs (defaults to the number of CPUs); lower it on
+    /// small or shared machines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub jobs: Option<u32>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -49,57 +133,85 @@ impl WithExamples for CargoBench {
         vec![
             Example {
                 description: "Run all benchmarks",
+                item: Self::default(),
+            },
+            Example {
+                description: "Run the benchmarks in benches/parser.rs",
+                item: Self {
+                    bench_target: Some("parser".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run benchmarks whose name contains a filter",
                 item: Self {
-                    package: None,
-                    bench_name: None,
-                    baseline: None,
-                    quiet: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    filter: Some("parse_large".into()),
+                    ..Self::default()
                 },
             },
             Example {
-                description: "Run a specific benchmark",
+                description: "Run the benchmarks of every workspace package but one",
                 item: Self {
-                    package: None,
-                    bench_name: Some("my_benchmark".into()),
-                    baseline: None,
-                    quiet: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    workspace: Some(true),
+                    exclude: Some(vec!["slow-benches".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run benchmarks with a custom profile",
+                item: Self {
+                    profile: Some("release-lto".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run benchmarks gated behind a feature",
+                item: Self {
+                    features: Some("bench".into()),
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Run benchmarks for a specific package",
                 item: Self {
                     package: Some("my-lib".into()),
-                    bench_name: None,
-                    baseline: None,
-                    quiet: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Run benchmarks with a baseline for comparison",
                 item: Self {
-                    package: None,
-                    bench_name: None,
                     baseline: Some("main".into()),
-                    quiet: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Compare benchmarks against a saved baseline",
+                item: Self {
+                    compare_baseline: Some("main".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Fail if any benchmark is more than 5% slower than the main baseline",
+                item: Self {
+                    compare_baseline: Some("main".into()),
+                    max_regression_percent: Some(5),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check that the benchmarks compile",
+                item: Self {
+                    no_run: Some(true),
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Run all benchmarks with compact output",
                 item: Self {
-                    package: None,
-                    bench_name: None,
-                    baseline: None,
                     quiet: Some(true),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
         ]
@@ -108,12 +220,27 @@ impl WithExamples for CargoBench {
 
 impl Tool<CargoTools> for CargoBench {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        if self.baseline.is_some() && self.compare_baseline.is_some() {
+            return Err(anyhow!(
+                "baseline and compare_baseline can't be combined; compare first, then save \
+                 a new baseline in a separate run"
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
 
         let mut args = vec!["bench"];
 
@@ -121,19 +248,220 @@ impl Tool<CargoTools> for CargoBench {
             args.push("--quiet");
         }
 
-        if let Some(ref package) = self.package {
-            args.extend_from_slice(&["--package", package]);
+        if let Some(ref profile) = self.profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
         }
 
-        if let Some(ref bench_name) = self.bench_name {
-            args.push(bench_name);
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
         }
 
+        let jobs_str;
+        if let Some(jobs) = self.jobs {
+            jobs_str = jobs.to_string();
+            args.extend_from_slice(&["--jobs", &jobs_str]);
+        }
+
+        if let Some(ref bench_target) = self.bench_target {
+            args.extend_from_slice(&["--bench", bench_target]);
+        }
+
+        if self.no_run.unwrap_or(false) {
+            args.push("--no-run");
+        }
+
+        if let Some(ref filter) = self.filter {
+            args.push(filter);
+        }
+
+        // Options for the benchmark harness go after --
+        let mut harness_args = Vec::new();
+
         if let Some(ref baseline) = self.baseline {
-            args.extend_from_slice(&["--", "--save-baseline", baseline]);
+            harness_args.extend_from_slice(&["--save-baseline", baseline]);
+        }
+
+        if let Some(ref compare_baseline) = self.compare_baseline {
+            harness_args.extend_from_slice(&["--baseline", compare_baseline]);
+        }
+
+        let exclude = self.exclude.unwrap_or_default();
+        let packages = if self.workspace.unwrap_or(false) || !exclude.is_empty() {
+            let packages = bench_packages(
+                &project_path,
+                toolchain.as_deref(),
+                self.bench_target.as_deref(),
+            )?;
+            let packages: Vec<String> = packages
+                .into_iter()
+                .filter(|package| !exclude.contains(package))
+                .collect();
+            if packages.is_empty() {
+                return Err(anyhow!(
+                    "No workspace package to benchmark has bench targets"
+                ));
+            }
+            packages.into_iter().map(Some).collect()
+        } else {
+            vec![self.package]
+        };
+
+        // The timeout covers all packages together
+        let deadline = self
+            .timeout_secs
+            .map(|timeout_secs| Instant::now() + Duration::from_secs(timeout_secs));
+        let mut result = String::new();
+        let mut compared = false;
+        let mut regressions = Vec::new();
+        for package in &packages {
+            let mut run_args = args.clone();
+            if let Some(package) = package {
+                run_args.extend_from_slice(&["--package", package]);
+            }
+            if !harness_args.is_empty() {
+                run_args.push("--");
+                run_args.extend_from_slice(&harness_args);
+            }
+
+            let cmd = create_cargo_command(
+                &run_args,
+                toolchain.as_deref(),
+                self.cargo_env.as_ref(),
+                &cargo_options,
+            );
+            let output = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        result.push_str(&format!(
+                            "⏱️  Out of time, skipped {}\n",
+                            package.as_deref().unwrap_or_default()
+                        ));
+                        continue;
+                    }
+                    run_cargo_command_with_timeout(cmd, &project_path, remaining)?
+                }
+                None => run_cargo_command(cmd, &project_path)?,
+            };
+
+            let command_name = match package {
+                Some(package) if packages.len() > 1 => format!("cargo bench: {package}"),
+                _ => "cargo bench".into(),
+            };
+            result.push_str(&format_cargo_output(&output, &project_path, &command_name));
+
+            let benches = parse_criterion_output(&output.stdout);
+            if let Some(max_regression) = self.max_regression_percent {
+                for bench in &benches {
+                    let Some(ref change) = bench.change else {
+                        continue;
+                    };
+                    compared = true;
+                    if change.estimate > f64::from(max_regression) {
+                        regressions.push(format!("  {}: {:+.2}%\n", bench.name, change.estimate));
+                    }
+                }
+            }
+            if !benches.is_empty() {
+                result.push_str(&summarize_changes(
+                    &benches,
+                    self.compare_baseline.as_deref(),
+                ));
+                result.push_str(&format!(
+                    "📊 Benchmark results:\n{}\n",
+                    serde_json::to_string_pretty(&benches)?
+                ));
+            }
+        }
+
+        if let Some(max_regression) = self.max_regression_percent {
+            // Just the regressions, since errors aren't paged like long results
+            if !regressions.is_empty() {
+                return Err(anyhow!(
+                    "❌ {} benchmark(s) regressed by more than {max_regression}%:\n{}",
+                    regressions.len(),
+                    regressions.concat()
+                ));
+            }
+            if compared {
+                result.push_str(&format!(
+                    "✅ No benchmark regressed by more than {max_regression}%\n"
+                ));
+            } else {
+                result.push_str(
+                    "⚠️  No benchmark was compared against a baseline, so \
+                     max_regression_percent wasn't checked\n",
+                );
+            }
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo bench")
+        Ok(result)
+    }
+}
+
+/// Workspace members with bench targets (just the given one, if any)
+fn bench_packages(
+    project_path: &PathBuf,
+    toolchain: Option<&str>,
+    bench_target: Option<&str>,
+) -> Result<Vec<String>> {
+    let cmd = create_cargo_command(
+        &["metadata", "--no-deps", "--format-version", "1"],
+        toolchain,
+        None,
+        &CargoOptions::default(),
+    );
+    let output = run_cargo_command(cmd, project_path)?;
+    if !output.status.success() {
+        return Err(anyhow!("cargo metadata failed:\n{}", output.stderr));
+    }
+    let metadata: Value = serde_json::from_str(&output.stdout)?;
+
+    let packages = metadata["packages"].as_array().into_iter().flatten();
+    Ok(packages
+        .filter(|package| {
+            package["targets"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|target| {
+                    target["kind"]
+                        .as_array()
+                        .is_some_and(|kinds| kinds.iter().any(|kind| kind == "bench"))
+                        && bench_target.is_none_or(|name| target["name"] == name)
+                })
+        })
+        .filter_map(|package| package["name"].as_str().map(String::from))
+        .collect())
+}
+
+/// Count regressions and improvements, e.g. "🔺 1 regressed, 🟢 2 improved, ➖ 5 unchanged"
+fn summarize_changes(benches: &[BenchResult], baseline: Option<&str>) -> String {
+    let compared = benches
+        .iter()
+        .filter(|bench| bench.change.is_some())
+        .count();
+    if compared == 0 {
+        return String::new();
     }
+
+    let regressed = benches.iter().filter(|bench| bench.regressed()).count();
+    let improved = benches.iter().filter(|bench| bench.improved()).count();
+    let against = match baseline {
+        Some(baseline) => format!("baseline '{baseline}'"),
+        None => "the previous run".into(),
+    };
+    format!(
+        "Compared against {against}: 🔺 {regressed} regressed, 🟢 {improved} improved, \
+         ➖ {} unchanged\n\n",
+        compared - regressed - improved
+    )
 }
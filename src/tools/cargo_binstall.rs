@@ -0,0 +1,157 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::{CargoOptions, GlobalOptions};
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Install a prebuilt binary of a Rust tool (such as a cargo extension) with cargo binstall,
+/// which is much faster than compiling it with cargo install (requires cargo-binstall)
+///
+/// By default only prebuilt binaries are used, and the install fails if none is available
+/// for this platform. Set fallback_to_source to compile from source instead.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_binstall")]
+pub struct CargoBinstall {
+    /// Name of the crate to install (e.g., 'cargo-nextest')
+    #[arg(long)]
+    pub crate_name: String,
+
+    /// Optional version requirement to install (e.g., '0.9.72' or '^0.9')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub version: Option<String>,
+
+    /// Compile from source with cargo install if no prebuilt binary is found (defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub fallback_to_source: Option<bool>,
+
+    /// Optional target triple of the binary to install (defaults to the host)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Force overwriting existing crates or binaries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub force: Option<bool>,
+
+    /// Only show what would be installed, without installing anything
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub dry_run: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoBinstall {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Install a prebuilt cargo-nextest",
+                item: Self {
+                    crate_name: "cargo-nextest".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install a pinned version, compiling it if there is no prebuilt binary",
+                item: Self {
+                    crate_name: "cargo-expand".into(),
+                    version: Some("1.0.88".into()),
+                    fallback_to_source: Some(true),
+                    cargo_options: CargoOptions {
+                        global: GlobalOptions {
+                            locked: Some(true),
+                            ..GlobalOptions::default()
+                        },
+                        ..CargoOptions::default()
+                    },
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check which binary would be installed",
+                item: Self {
+                    crate_name: "cargo-deny".into(),
+                    dry_run: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoBinstall {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        ensure_cargo_subcommand("binstall", "cargo-binstall")?;
+
+        // Installing doesn't require a Rust project
+        let working_dir = self.cargo_options.working_dir(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // There is nobody to answer the confirmation prompt
+        let mut args = vec!["binstall", "--no-confirm"];
+
+        if let Some(ref version) = self.version {
+            args.extend_from_slice(&["--version", version]);
+        }
+
+        if !self.fallback_to_source.unwrap_or(false) {
+            args.extend_from_slice(&["--disable-strategies", "compile"]);
+        }
+
+        // Cargo doesn't pass its global --locked on to binstall's own compile fallback
+        if cargo_options.global.is_locked() {
+            args.push("--locked");
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--targets", target]);
+        }
+
+        if self.force.unwrap_or(false) {
+            args.push("--force");
+        }
+
+        if self.dry_run.unwrap_or(false) {
+            args.push("--dry-run");
+        }
+
+        args.push(&self.crate_name);
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &working_dir, "cargo binstall")
+    }
+}
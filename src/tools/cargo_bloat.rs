@@ -0,0 +1,156 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Find out what takes up the most space in a binary with cargo bloat (requires cargo-bloat)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_bloat")]
+pub struct CargoBloat {
+    /// Optional package name to analyze (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional binary target to analyze
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Optional example target to analyze
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub example: Option<String>,
+
+    /// Analyze a release build (defaults to true, since debug builds aren't representative)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Group sizes by crate instead of listing individual functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub crates: Option<bool>,
+
+    /// Number of entries to show (defaults to 20)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub top: Option<u32>,
+
+    /// Optional filter to only show functions from a crate, or matching a regex
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoBloat {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show the largest functions in the release binary",
+                item: Self::default(),
+            },
+            Example {
+                description: "Show which crates contribute most to binary size",
+                item: Self {
+                    crates: Some(true),
+                    top: Some(10),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the largest functions from the regex crate",
+                item: Self {
+                    filter: Some("regex".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoBloat {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("bloat", "cargo-bloat")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let top = self.top.unwrap_or(20).to_string();
+        let mut args = vec!["bloat", "-n", &top];
+
+        if self.release.unwrap_or(true) {
+            args.push("--release");
+        }
+
+        if self.crates.unwrap_or(false) {
+            args.push("--crates");
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        if let Some(ref example) = self.example {
+            args.extend_from_slice(&["--example", example]);
+        }
+
+        if let Some(ref filter) = self.filter {
+            args.extend_from_slice(&["--filter", filter]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo bloat")
+    }
+}
@@ -1,6 +1,8 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
-use anyhow::Result;
+use crate::tools::cargo_options::{CargoOptions, GlobalOptions};
+use crate::tools::cargo_utils::create_cargo_command;
+use crate::tools::diagnostics::{self, DiagnosticOptions, execute_with_diagnostics};
+use anyhow::{Result, anyhow};
 use mcplease::{
     traits::{Tool, WithExamples},
     types::Example,
@@ -9,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Build the project with cargo build
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+///
+/// Along with the output, returns the compiler's errors and warnings as JSON, with their
+/// code, location and rendered text.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_build")]
 pub struct CargoBuild {
     /// Optional package name to build (for workspaces)
@@ -22,6 +27,49 @@ pub struct CargoBuild {
     #[arg(long)]
     pub release: Option<bool>,
 
+    /// Optional custom profile to build with (e.g., 'release-lto'); can't be combined with release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Optional target triple to build for (e.g., 'x86_64-unknown-linux-musl')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Optional binary name to build (only this binary target)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Build only this package's library
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub diagnostic_options: DiagnosticOptions,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -38,38 +86,63 @@ impl WithExamples for CargoBuild {
         vec![
             Example {
                 description: "Build the project in debug mode",
-                item: Self {
-                    package: None,
-                    release: None,
-                    toolchain: None,
-                    cargo_env: None,
-                },
+                item: Self::default(),
             },
             Example {
                 description: "Build the project in release mode",
                 item: Self {
-                    package: None,
                     release: Some(true),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Build a specific package",
                 item: Self {
                     package: Some("my-lib".into()),
-                    release: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Build with nightly toolchain",
                 item: Self {
-                    package: None,
-                    release: None,
                     toolchain: Some("nightly".into()),
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build a single binary with specific features",
+                item: Self {
+                    bin: Some("my-binary".into()),
+                    features: Some("feature1 feature2".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build only the library for a foreign target",
+                item: Self {
+                    lib: Some(true),
+                    target: Some("wasm32-unknown-unknown".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build with a custom profile from Cargo.toml",
+                item: Self {
+                    profile: Some("release-lto".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build in a separate target directory",
+                item: Self {
+                    cargo_options: CargoOptions {
+                        global: GlobalOptions {
+                            target_dir: Some("target-mcp".into()),
+                            ..GlobalOptions::default()
+                        },
+                        ..CargoOptions::default()
+                    },
+                    ..Self::default()
                 },
             },
         ]
@@ -78,15 +151,38 @@ impl WithExamples for CargoBuild {
 
 impl Tool<CargoTools> for CargoBuild {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        if self.release.unwrap_or(false) && self.profile.is_some() {
+            return Err(anyhow!(
+                "release and profile can't be combined; use profile: 'release' instead"
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Use profile from args, session default, or none. Asking for release or not
+        // overrides the default too.
+        let profile = match self.release {
+            Some(_) => self.profile,
+            None => self
+                .profile
+                .or_else(|| state.get_default_profile(None).unwrap_or(None)),
+        };
+
+        let mut args = vec!["build", "--message-format", diagnostics::MESSAGE_FORMAT];
 
-        let mut args = vec!["build"];
-        
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
@@ -95,7 +191,47 @@ impl Tool<CargoTools> for CargoBuild {
             args.push("--release");
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo build")
+        if let Some(ref profile) = profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_with_diagnostics(
+            state,
+            cmd,
+            &project_path,
+            "cargo build",
+            &self.diagnostic_options,
+            cargo_options.global.keeps_color(),
+        )
     }
 }
@@ -0,0 +1,148 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, ensure_rustup_component, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which cargo command to run with cargo careful
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CarefulCommand {
+    #[default]
+    Test,
+    Run,
+}
+
+/// Run tests or a binary against a standard library built with extra debug assertions and
+/// UB checks, using cargo careful (requires cargo-careful)
+///
+/// Much faster than cargo_miri, but catches fewer problems. Uses the nightly toolchain
+/// unless another toolchain is given, and installs the rust-src component if it is missing.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_careful")]
+pub struct CargoCareful {
+    /// Whether to run 'test' (the default) or 'run'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<CarefulCommand>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional test name filter (test command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_name: Option<String>,
+
+    /// Optional binary name to run (run command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Arguments to pass to the binary after `--` (run command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub args: Option<Vec<String>>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional nightly toolchain to use (defaults to 'nightly')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoCareful {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run all tests with a checked standard library",
+                item: Self::default(),
+            },
+            Example {
+                description: "Run a binary with a checked standard library",
+                item: Self {
+                    command: Some(CarefulCommand::Run),
+                    bin: Some("my-binary".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoCareful {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("careful", "cargo-careful")?;
+
+        // cargo careful rebuilds the standard library, which needs nightly and its sources
+        let toolchain = self.toolchain.unwrap_or_else(|| "nightly".into());
+        let install_note = ensure_rustup_component("rust-src", &toolchain)?;
+
+        let command = self.command.unwrap_or_default();
+
+        let mut args = vec!["careful"];
+        args.push(match command {
+            CarefulCommand::Test => "test",
+            CarefulCommand::Run => "run",
+        });
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        match command {
+            CarefulCommand::Test => {
+                if let Some(ref test_name) = self.test_name {
+                    args.push(test_name);
+                }
+            }
+            CarefulCommand::Run => {
+                if let Some(ref bin) = self.bin {
+                    args.extend_from_slice(&["--bin", bin]);
+                }
+
+                if let Some(ref binary_args) = self.args
+                    && !binary_args.is_empty()
+                {
+                    args.push("--");
+                    args.extend(binary_args.iter().map(String::as_str));
+                }
+            }
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(
+            &args,
+            Some(&toolchain),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let result = execute_cargo_command(cmd, &project_path, "cargo careful")?;
+
+        match install_note {
+            Some(note) => Ok(format!("{note}\n{result}")),
+            None => Ok(result),
+        }
+    }
+}
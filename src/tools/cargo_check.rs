@@ -1,5 +1,7 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::create_cargo_command;
+use crate::tools::diagnostics::{self, DiagnosticOptions, execute_with_diagnostics};
 use anyhow::Result;
 use mcplease::{
     traits::{Tool, WithExamples},
@@ -9,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Run cargo check to verify the code compiles
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+///
+/// Along with the output, returns the compiler's errors and warnings as JSON, with their
+/// code, location and rendered text.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_check")]
 pub struct CargoCheck {
     /// Optional package name to check (for workspaces)
@@ -17,6 +22,44 @@ pub struct CargoCheck {
     #[arg(long)]
     pub package: Option<String>,
 
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Optional target triple to check for (e.g., 'wasm32-unknown-unknown')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Check all targets (lib, bins, tests, benches, examples)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_targets: Option<bool>,
+
+    /// Optional profile to check with (e.g., 'release' or 'dev-opt')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub diagnostic_options: DiagnosticOptions,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -33,33 +76,53 @@ impl WithExamples for CargoCheck {
         vec![
             Example {
                 description: "Basic cargo check in current project",
-                item: Self {
-                    package: None,
-                    toolchain: None,
-                    cargo_env: None,
-                },
+                item: Self::default(),
             },
             Example {
                 description: "Check a specific package in a workspace",
                 item: Self {
                     package: Some("my-lib".into()),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Check using nightly toolchain",
                 item: Self {
-                    package: None,
                     toolchain: Some("nightly".into()),
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check all targets including tests and examples",
+                item: Self {
+                    all_targets: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check with all features for a foreign target",
+                item: Self {
+                    all_features: Some(true),
+                    target: Some("wasm32-unknown-unknown".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check with build settings overridden for this call",
+                item: Self {
+                    cargo_options: CargoOptions {
+                        config: Some(vec![
+                            "build.jobs=2".into(),
+                            "profile.dev.debug=false".into(),
+                        ]),
+                        ..CargoOptions::default()
+                    },
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Check with custom environment variables",
                 item: Self {
-                    package: None,
-                    toolchain: None,
                     cargo_env: Some(
                         [
                             ("RUSTFLAGS".into(), "-D warnings".into()),
@@ -67,6 +130,7 @@ impl WithExamples for CargoCheck {
                         ]
                         .into(),
                     ),
+                    ..Self::default()
                 },
             },
         ]
@@ -75,20 +139,69 @@ impl WithExamples for CargoCheck {
 
 impl Tool<CargoTools> for CargoCheck {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
+        let project_path = self.cargo_options.project_path(state)?;
 
         // Use toolchain from args, session default, or none
         let toolchain = self
             .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
-        let mut args = vec!["check"];
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Use profile from args, session default, or none
+        let profile = self
+            .profile
+            .or_else(|| state.get_default_profile(None).unwrap_or(None));
+
+        let mut args = vec!["check", "--message-format", diagnostics::MESSAGE_FORMAT];
 
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo check")
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        if self.all_targets.unwrap_or(false) {
+            args.push("--all-targets");
+        }
+
+        if let Some(ref profile) = profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_with_diagnostics(
+            state,
+            cmd,
+            &project_path,
+            "cargo check",
+            &self.diagnostic_options,
+            cargo_options.global.keeps_color(),
+        )
     }
 }
@@ -1,5 +1,8 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, format_cargo_output, run_cargo_command, strip_ansi,
+};
 use anyhow::Result;
 use mcplease::{
     traits::{Tool, WithExamples},
@@ -9,7 +12,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Remove artifacts that cargo has generated in the past
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+///
+/// Only reports what would be removed unless `dry_run` is false, so the removal can be
+/// checked before anything is deleted.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_clean")]
 pub struct CargoClean {
     /// Optional package to clean artifacts for
@@ -17,6 +23,26 @@ pub struct CargoClean {
     #[arg(long)]
     pub package: Option<String>,
 
+    /// Only clean release artifacts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Only clean the documentation directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub doc: Option<bool>,
+
+    /// Only report what would be removed, without deleting anything (defaults to true). Set
+    /// to false to actually remove the artifacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub dry_run: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -31,20 +57,39 @@ pub struct CargoClean {
 impl WithExamples for CargoClean {
     fn examples() -> Vec<Example<Self>> {
         vec![
+            Example {
+                description: "See how much would be removed without deleting anything",
+                item: Self::default(),
+            },
             Example {
                 description: "Clean all build artifacts",
                 item: Self {
-                    package: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    dry_run: Some(false),
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Clean artifacts for a specific package",
                 item: Self {
                     package: Some("my-lib".into()),
-                    toolchain: None,
-                    cargo_env: None,
+                    dry_run: Some(false),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Clean only release artifacts",
+                item: Self {
+                    release: Some(true),
+                    dry_run: Some(false),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Clean only generated documentation",
+                item: Self {
+                    doc: Some(true),
+                    dry_run: Some(false),
+                    ..Self::default()
                 },
             },
         ]
@@ -53,20 +98,71 @@ impl WithExamples for CargoClean {
 
 impl Tool<CargoTools> for CargoClean {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
 
         let mut args = vec!["clean"];
-        
+
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo clean")
+        if self.release.unwrap_or(false) {
+            args.push("--release");
+        }
+
+        if self.doc.unwrap_or(false) {
+            args.push("--doc");
+        }
+
+        // Always preview first, so that the result says what was removed
+        let mut preview_args = args.clone();
+        preview_args.push("--dry-run");
+        let cmd = create_cargo_command(
+            &preview_args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let preview = run_cargo_command(cmd, &project_path)?;
+
+        if self.dry_run.unwrap_or(true) || !preview.status.success() {
+            return Ok(format_cargo_output(
+                &preview,
+                &project_path,
+                "cargo clean --dry-run",
+            ));
+        }
+
+        // Cargo leaves out the summary when quiet
+        let summary = preview
+            .stderr
+            .lines()
+            .find_map(|line| {
+                strip_ansi(line)
+                    .trim()
+                    .strip_prefix("Summary ")
+                    .map(String::from)
+            })
+            .unwrap_or_else(|| "unknown (cargo printed no summary)".to_string());
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+
+        let mut result = format!("🧹 Removing: {summary}\n\n");
+        result.push_str(&format_cargo_output(&output, &project_path, "cargo clean"));
+        Ok(result)
     }
 }
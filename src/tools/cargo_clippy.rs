@@ -1,5 +1,7 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::create_cargo_command;
+use crate::tools::diagnostics::{self, DiagnosticOptions, execute_with_diagnostics};
 use anyhow::Result;
 use mcplease::{
     traits::{Tool, WithExamples},
@@ -9,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Run cargo clippy for linting suggestions
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+///
+/// Along with the output, returns the compiler's errors and warnings as JSON, with their
+/// code, location and rendered text.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_clippy")]
 pub struct CargoClippy {
     /// Optional package name to lint (for workspaces)
@@ -17,6 +22,34 @@ pub struct CargoClippy {
     #[arg(long)]
     pub package: Option<String>,
 
+    /// Optional profile to lint with (e.g., 'release' or 'dev-opt')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub diagnostic_options: DiagnosticOptions,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -27,6 +60,37 @@ pub struct CargoClippy {
     #[arg(long)]
     pub fix: Option<bool>,
 
+    /// Allow fixes even if the working directory has uncommitted changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_dirty: Option<bool>,
+
+    /// Treat all warnings as errors (`-D warnings`). Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub deny_warnings: Option<bool>,
+
+    /// Lints to deny (e.g., ['clippy::unwrap_used'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub deny: Option<Vec<String>>,
+
+    /// Lints to warn on (e.g., ['clippy::pedantic'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub warn: Option<Vec<String>>,
+
+    /// Lints to allow (e.g., ['clippy::too_many_arguments'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow: Option<Vec<String>>,
+
+    /// Use this to get one compact line per diagnostic (file:line:col: level: message)
+    /// as the rendered text instead of the full output with code snippets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub short: Option<bool>,
+
     /// Optional environment variables to set for the cargo command
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(skip)]
@@ -38,38 +102,51 @@ impl WithExamples for CargoClippy {
         vec![
             Example {
                 description: "Basic cargo clippy in current project",
-                item: Self {
-                    package: None,
-                    toolchain: None,
-                    fix: None,
-                    cargo_env: None,
-                },
+                item: Self::default(),
             },
             Example {
                 description: "Run clippy on a specific package",
                 item: Self {
                     package: Some("my-lib".into()),
-                    toolchain: None,
-                    fix: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Run clippy with automatic fixes",
                 item: Self {
-                    package: None,
-                    toolchain: None,
                     fix: Some(true),
-                    cargo_env: None,
+                    allow_dirty: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Lint with all features enabled",
+                item: Self {
+                    all_features: Some(true),
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Run clippy with nightly toolchain",
                 item: Self {
-                    package: None,
                     toolchain: Some("nightly".into()),
-                    fix: None,
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Enable pedantic lints as warnings without failing on warnings",
+                item: Self {
+                    deny_warnings: Some(false),
+                    warn: Some(vec!["clippy::pedantic".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Compact output with an extra denied lint",
+                item: Self {
+                    deny: Some(vec!["clippy::unwrap_used".into()]),
+                    short: Some(true),
+                    ..Self::default()
                 },
             },
         ]
@@ -78,27 +155,95 @@ impl WithExamples for CargoClippy {
 
 impl Tool<CargoTools> for CargoClippy {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Use profile from args, session default, or none
+        let profile = self
+            .profile
+            .or_else(|| state.get_default_profile(None).unwrap_or(None));
 
         let mut args = vec!["clippy"];
-        
+
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
 
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if let Some(ref profile) = profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
         if self.fix.unwrap_or(false) {
             args.push("--fix");
         }
 
-        // Add clippy arguments
-        args.extend_from_slice(&["--", "-D", "warnings"]);
+        if self.allow_dirty.unwrap_or(false) {
+            args.push("--allow-dirty");
+        }
+
+        let message_format = if self.short.unwrap_or(false) {
+            diagnostics::MESSAGE_FORMAT_SHORT
+        } else {
+            diagnostics::MESSAGE_FORMAT
+        };
+        args.extend_from_slice(&["--message-format", message_format]);
+
+        // Add clippy arguments; later lint levels override earlier ones
+        args.push("--");
+
+        if self.deny_warnings.unwrap_or(true) {
+            args.extend_from_slice(&["-D", "warnings"]);
+        }
+
+        for lint in self.allow.iter().flatten() {
+            args.extend_from_slice(&["-A", lint]);
+        }
+
+        for lint in self.warn.iter().flatten() {
+            args.extend_from_slice(&["-W", lint]);
+        }
+
+        for lint in self.deny.iter().flatten() {
+            args.extend_from_slice(&["-D", lint]);
+        }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo clippy")
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_with_diagnostics(
+            state,
+            cmd,
+            &project_path,
+            "cargo clippy",
+            &self.diagnostic_options,
+            cargo_options.global.keeps_color(),
+        )
     }
 }
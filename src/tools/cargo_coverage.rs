@@ -0,0 +1,307 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path};
+
+/// How to report coverage results
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    schemars::JsonSchema,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageReport {
+    #[default]
+    Summary,
+    Lcov,
+    Files,
+}
+
+/// Measure test coverage with cargo llvm-cov (requires cargo-llvm-cov)
+///
+/// Always reports uncovered line ranges per file so tests can be targeted at untested code.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_coverage")]
+pub struct CargoCoverage {
+    /// Report mode: 'summary' (total coverage, the default), 'files' (per-file coverage),
+    /// or 'lcov' (also returns the path of the generated lcov.info file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub report: Option<CoverageReport>,
+
+    /// Optional package name to measure (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Measure coverage for the whole workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional test name filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_name: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Optional regex of file paths to leave out of the report (e.g., 'tests/|generated')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub ignore_filename_regex: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoCoverage {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Measure total coverage and list uncovered lines",
+                item: Self::default(),
+            },
+            Example {
+                description: "Show per-file coverage for a workspace package",
+                item: Self {
+                    report: Some(CoverageReport::Files),
+                    package: Some("my-lib".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Generate an lcov report for the parser tests only",
+                item: Self {
+                    report: Some(CoverageReport::Lcov),
+                    test_name: Some("parser".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoCoverage {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("llvm-cov", "cargo-llvm-cov")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let lcov_path = cargo_options
+            .global
+            .target_path(&project_path)
+            .join("llvm-cov")
+            .join("lcov.info");
+        let lcov_path_str = lcov_path.to_string_lossy().into_owned();
+
+        let mut args = vec!["llvm-cov", "--lcov", "--output-path", &lcov_path_str];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if let Some(ref regex) = self.ignore_filename_regex {
+            args.extend_from_slice(&["--ignore-filename-regex", regex]);
+        }
+
+        if let Some(ref test_name) = self.test_name {
+            args.extend_from_slice(&["--", test_name]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo llvm-cov");
+
+        if !output.status.success() {
+            return Ok(result);
+        }
+
+        let lcov = std::fs::read_to_string(&lcov_path)?;
+        let files = parse_lcov(&lcov);
+        let report = self.report.unwrap_or_default();
+
+        let (covered, total) = files
+            .iter()
+            .fold((0, 0), |(c, t), file| (c + file.covered, t + file.total));
+        result.push_str(&format!(
+            "📊 Line coverage: {covered}/{total} ({})\n",
+            percent(covered, total)
+        ));
+
+        if report == CoverageReport::Lcov {
+            result.push_str(&format!("📄 lcov report: {}\n", lcov_path.display()));
+        }
+
+        if report == CoverageReport::Files {
+            result.push_str("\n📁 Coverage by file:\n");
+            for file in &files {
+                result.push_str(&format!(
+                    "  {}: {}/{} ({})\n",
+                    relative(&file.path, &project_path),
+                    file.covered,
+                    file.total,
+                    percent(file.covered, file.total)
+                ));
+            }
+        }
+
+        let uncovered: Vec<_> = files.iter().filter(|f| !f.uncovered.is_empty()).collect();
+        if uncovered.is_empty() {
+            result.push_str("\n✅ No uncovered lines\n");
+        } else {
+            result.push_str("\n🕳️  Uncovered lines:\n");
+            for file in uncovered {
+                let ranges = file
+                    .uncovered
+                    .iter()
+                    .map(|&(start, end)| {
+                        if start == end {
+                            start.to_string()
+                        } else {
+                            format!("{start}-{end}")
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                result.push_str(&format!(
+                    "  {}: {ranges}\n",
+                    relative(&file.path, &project_path)
+                ));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Line coverage for a single source file
+struct FileCoverage {
+    path: String,
+    covered: u64,
+    total: u64,
+    /// Inclusive ranges of instrumented lines that were never executed
+    uncovered: Vec<(u64, u64)>,
+}
+
+/// Parse `SF:` / `DA:<line>,<count>` records from an lcov report. A range of uncovered
+/// lines continues across non-instrumented lines until a covered line is reached.
+fn parse_lcov(lcov: &str) -> Vec<FileCoverage> {
+    let mut files = vec![];
+    let mut current: Option<FileCoverage> = None;
+    let mut open_range: Option<(u64, u64)> = None;
+
+    for line in lcov.lines() {
+        if let Some(path) = line.strip_prefix("SF:") {
+            current = Some(FileCoverage {
+                path: path.to_string(),
+                covered: 0,
+                total: 0,
+                uncovered: vec![],
+            });
+            open_range = None;
+        } else if let Some(data) = line.strip_prefix("DA:")
+            && let Some(file) = current.as_mut()
+        {
+            let mut parts = data.split(',').map(str::parse::<u64>);
+            let (Some(Ok(line_number)), Some(Ok(count))) = (parts.next(), parts.next()) else {
+                continue;
+            };
+
+            file.total += 1;
+            if count > 0 {
+                file.covered += 1;
+                file.uncovered.extend(open_range.take());
+            } else {
+                let (start, _) = open_range.unwrap_or((line_number, line_number));
+                open_range = Some((start, line_number));
+            }
+        } else if line == "end_of_record"
+            && let Some(mut file) = current.take()
+        {
+            file.uncovered.extend(open_range.take());
+            files.push(file);
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn percent(covered: u64, total: u64) -> String {
+    if total == 0 {
+        "n/a".into()
+    } else {
+        format!("{:.1}%", covered as f64 * 100.0 / total as f64)
+    }
+}
+
+fn relative(path: &str, project_path: &Path) -> String {
+    Path::new(path)
+        .strip_prefix(project_path)
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
@@ -0,0 +1,172 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{ensure_program, execute_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, process::Command};
+
+/// Which command to run for the foreign target
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossCommand {
+    #[default]
+    Build,
+    Check,
+    Test,
+}
+
+/// Build, check or test for a foreign target with cross (requires cross, and Docker or
+/// Podman)
+///
+/// Runs inside a container with the right linker and system libraries, and runs tests
+/// under emulation where needed.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_cross")]
+pub struct CargoCross {
+    /// Target triple to build for (e.g., 'aarch64-unknown-linux-gnu', 'armv7-unknown-linux-gnueabihf')
+    #[arg(long)]
+    pub target: String,
+
+    /// Which command to run: 'build' (the default), 'check' or 'test'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<CrossCommand>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Build in release mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the default features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cross command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoCross {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Build a release binary for 64-bit ARM Linux",
+                item: Self {
+                    target: "aarch64-unknown-linux-gnu".into(),
+                    release: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run the tests on a 32-bit ARM target under emulation",
+                item: Self {
+                    target: "armv7-unknown-linux-gnueabihf".into(),
+                    command: Some(CrossCommand::Test),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoCross {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_program("cross", "cross")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Without release given, build with the session's default profile, if any
+        let default_profile = match self.release {
+            Some(_) => None,
+            None => state.get_default_profile(None)?,
+        };
+
+        let mut args = vec![match self.command.unwrap_or_default() {
+            CrossCommand::Build => "build",
+            CrossCommand::Check => "check",
+            CrossCommand::Test => "test",
+        }];
+
+        args.extend_from_slice(&["--target", &self.target]);
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.release.unwrap_or(false) {
+            args.push("--release");
+        }
+
+        if let Some(ref profile) = default_profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        // cross is a drop-in replacement for cargo, and takes the toolchain the same way
+        let mut cmd = Command::new("cross");
+        if let Some(ref toolchain) = toolchain {
+            cmd.arg(format!("+{toolchain}"));
+        }
+        cargo_options.apply(&mut cmd, &args);
+        if let Some(ref env) = self.cargo_env {
+            cmd.envs(env);
+        }
+
+        execute_cargo_command(cmd, &project_path, "cross")
+    }
+}
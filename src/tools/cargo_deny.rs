@@ -0,0 +1,208 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// A cargo deny check category
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DenyCheck {
+    Advisories,
+    Bans,
+    Licenses,
+    Sources,
+}
+
+impl DenyCheck {
+    fn as_str(self) -> &'static str {
+        match self {
+            DenyCheck::Advisories => "advisories",
+            DenyCheck::Bans => "bans",
+            DenyCheck::Licenses => "licenses",
+            DenyCheck::Sources => "sources",
+        }
+    }
+}
+
+/// Check dependencies against advisory, ban, license and source policies with cargo deny
+/// (requires cargo-deny)
+///
+/// Returns a structured list of violations identifying the offending crate and rule.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_deny")]
+pub struct CargoDeny {
+    /// Checks to run: 'advisories', 'bans', 'licenses', 'sources' (defaults to all)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub checks: Option<Vec<DenyCheck>>,
+
+    /// Optional path to the deny.toml configuration (relative to the project)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long = "deny-config")]
+    pub deny_config: Option<String>,
+
+    /// Space-separated list of features to activate when resolving dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features when resolving dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoDeny {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run all cargo deny checks",
+                item: Self::default(),
+            },
+            Example {
+                description: "Check only dependency licenses",
+                item: Self {
+                    checks: Some(vec![DenyCheck::Licenses]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check bans and sources with all features enabled",
+                item: Self {
+                    checks: Some(vec![DenyCheck::Bans, DenyCheck::Sources]),
+                    all_features: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoDeny {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("deny", "cargo-deny")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["deny", "--format", "json", "--color", "never"];
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        args.push("check");
+
+        if let Some(ref config) = self.deny_config {
+            args.extend_from_slice(&["--config", config]);
+        }
+
+        for check in self.checks.iter().flatten() {
+            args.push(check.as_str());
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        let violations = parse_violations(&output.stderr);
+        let mut result = String::new();
+
+        // The JSON diagnostics are replaced by the structured summary below
+        if !violations.is_empty() || output.status.success() {
+            output.stderr.clear();
+        }
+        result.push_str(&format_cargo_output(
+            &output,
+            &project_path,
+            "cargo deny check",
+        ));
+
+        result.push_str(&format!("🚫 Violations ({}):\n", violations.len()));
+        result.push_str(&serde_json::to_string_pretty(&violations)?);
+        result.push('\n');
+
+        Ok(result)
+    }
+}
+
+/// Extract error and warning diagnostics from cargo deny's JSON-lines output
+fn parse_violations(stderr: &str) -> Vec<Value> {
+    stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|message| message["type"] == "diagnostic")
+        .filter_map(|message| {
+            let fields = &message["fields"];
+            let severity = fields["severity"].as_str()?;
+            if severity != "error" && severity != "warning" {
+                return None;
+            }
+
+            let crates: Vec<String> = fields["graphs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|graph| {
+                    let krate = &graph["Krate"];
+                    Some(format!(
+                        "{}@{}",
+                        krate["name"].as_str()?,
+                        krate["version"].as_str()?
+                    ))
+                })
+                .collect();
+
+            Some(json!({
+                "severity": severity,
+                "rule": fields["code"],
+                "message": fields["message"],
+                "crates": crates,
+            }))
+        })
+        .collect()
+}
@@ -0,0 +1,215 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+/// Which cargo dist command to run
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DistCommand {
+    #[default]
+    Plan,
+    Build,
+}
+
+/// Which artifacts cargo dist should plan or build
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum DistArtifacts {
+    Host,
+    Local,
+    Global,
+    All,
+}
+
+/// Plan or build release artifacts (archives, installers) with cargo dist (requires cargo-dist)
+///
+/// 'plan' lists what a release would contain without building anything; 'build' produces
+/// the artifacts. Both report each artifact with its target triples and path.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_dist")]
+pub struct CargoDist {
+    /// Which command to run: 'plan' (the default) or 'build'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<DistCommand>,
+
+    /// Which artifacts to include: 'host' (the default for build: only what this machine can
+    /// build), 'local' (per-target archives), 'global' (installers and checksums) or 'all'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub artifacts: Option<DistArtifacts>,
+
+    /// Optional target triples to limit the release to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<Vec<String>>,
+
+    /// Optional release tag to plan for (e.g., 'v1.2.0' or 'my-app-v1.2.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Return the raw JSON manifest instead of a summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub json: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoDist {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show what the next release would contain",
+                item: Self::default(),
+            },
+            Example {
+                description: "Build the artifacts this machine can produce",
+                item: Self {
+                    command: Some(DistCommand::Build),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Plan a release of one app for two targets",
+                item: Self {
+                    tag: Some("my-app-v1.2.0".into()),
+                    target: Some(vec![
+                        "x86_64-unknown-linux-gnu".into(),
+                        "aarch64-apple-darwin".into(),
+                    ]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoDist {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("dist", "cargo-dist")?;
+
+        let command = self.command.unwrap_or_default();
+        let mut args = vec!["dist"];
+        args.push(match command {
+            DistCommand::Plan => "plan",
+            DistCommand::Build => "build",
+        });
+        args.extend_from_slice(&["--output-format", "json"]);
+
+        let artifacts = self.artifacts.or(match command {
+            DistCommand::Build => Some(DistArtifacts::Host),
+            DistCommand::Plan => None,
+        });
+        if let Some(artifacts) = artifacts {
+            args.push(match artifacts {
+                DistArtifacts::Host => "--artifacts=host",
+                DistArtifacts::Local => "--artifacts=local",
+                DistArtifacts::Global => "--artifacts=global",
+                DistArtifacts::All => "--artifacts=all",
+            });
+        }
+
+        for target in self.target.iter().flatten() {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        if let Some(ref tag) = self.tag {
+            args.extend_from_slice(&["--tag", tag]);
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        if !self.json.unwrap_or(false)
+            && let Ok(manifest) = serde_json::from_str::<Value>(&output.stdout)
+        {
+            output.stdout = summarize_manifest(&manifest);
+        }
+
+        let command_name = match command {
+            DistCommand::Plan => "cargo dist plan",
+            DistCommand::Build => "cargo dist build",
+        };
+        Ok(format_cargo_output(&output, &project_path, command_name))
+    }
+}
+
+/// List each release with its artifacts, and every target triple involved
+fn summarize_manifest(manifest: &Value) -> String {
+    let artifacts = &manifest["artifacts"];
+    let mut triples = BTreeSet::new();
+    let mut summary = String::new();
+
+    for release in manifest["releases"].as_array().into_iter().flatten() {
+        summary.push_str(&format!(
+            "🚀 {} {}\n",
+            release["app_name"].as_str().unwrap_or("?"),
+            release["app_version"].as_str().unwrap_or("")
+        ));
+
+        for name in release["artifacts"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            let artifact = &artifacts[name];
+            let targets: Vec<&str> = artifact["target_triples"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect();
+            triples.extend(targets.iter().copied());
+
+            summary.push_str(&format!(
+                "  📦 {name} ({})",
+                artifact["kind"].as_str().unwrap_or("artifact")
+            ));
+            if !targets.is_empty() {
+                summary.push_str(&format!(" [{}]", targets.join(", ")));
+            }
+            summary.push('\n');
+            if let Some(path) = artifact["path"].as_str() {
+                summary.push_str(&format!("     {path}\n"));
+            }
+        }
+    }
+
+    if summary.is_empty() {
+        return "ℹ️  No releases in the manifest\n".into();
+    }
+
+    if !triples.is_empty() {
+        summary.push_str(&format!(
+            "\n🎯 Target triples: {}\n",
+            triples.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    summary
+}
@@ -0,0 +1,166 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path};
+
+/// Build documentation for the project with cargo doc
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_doc")]
+pub struct CargoDoc {
+    /// Optional package name to document (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Don't build documentation for dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_deps: Option<bool>,
+
+    /// Include non-public items in the documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub document_private_items: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoDoc {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Build documentation for the project and its dependencies",
+                item: Self::default(),
+            },
+            Example {
+                description: "Build documentation for only the workspace crates",
+                item: Self {
+                    no_deps: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Document private items of a specific package",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    document_private_items: Some(true),
+                    no_deps: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build documentation with all features enabled",
+                item: Self {
+                    all_features: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoDoc {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["doc"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.no_deps.unwrap_or(false) {
+            args.push("--no-deps");
+        }
+
+        if self.document_private_items.unwrap_or(false) {
+            args.push("--document-private-items");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo doc");
+
+        if let Some(index) = generated_index(&output.stderr) {
+            result.push_str(&format!("📚 Documentation index: {}\n", index.display()));
+            if let Some(root) = index.parent().and_then(Path::parent) {
+                result.push_str(&format!("📂 Documentation root: {}\n", root.display()));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Find the index.html path from cargo doc's "Generated" line
+pub fn generated_index(stderr: &str) -> Option<&Path> {
+    stderr.lines().find_map(|line| {
+        let path = line.trim().strip_prefix("Generated ")?;
+        // "Generated /path/index.html and 3 other files"
+        let path = path.split(" and ").next().unwrap_or(path);
+        Some(Path::new(path))
+    })
+}
@@ -0,0 +1,177 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Show the result of macro expansion with cargo expand (requires cargo-expand)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_expand")]
+pub struct CargoExpand {
+    /// Optional module path or item to limit expansion to (e.g., 'parser::Token')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub item: Option<String>,
+
+    /// Optional package name to expand (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Expand only this package's library
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Optional binary name to expand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Optional integration test name to expand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test: Option<String>,
+
+    /// Optional example name to expand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub example: Option<String>,
+
+    /// Expand the crate as compiled for tests (includes #[cfg(test)] code)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub tests: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoExpand {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Expand the whole crate",
+                item: Self::default(),
+            },
+            Example {
+                description: "Expand a single item to see what a derive generates",
+                item: Self {
+                    item: Some("models::User".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Expand a module of the library in a workspace package",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    lib: Some(true),
+                    item: Some("parser".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Expand test code",
+                item: Self {
+                    tests: Some(true),
+                    item: Some("tests".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoExpand {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("expand", "cargo-expand")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["expand", "--color", "never"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        if let Some(ref test) = self.test {
+            args.extend_from_slice(&["--test", test]);
+        }
+
+        if let Some(ref example) = self.example {
+            args.extend_from_slice(&["--example", example]);
+        }
+
+        if self.tests.unwrap_or(false) {
+            args.push("--tests");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if let Some(ref item) = self.item {
+            args.push(item);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo expand")
+    }
+}
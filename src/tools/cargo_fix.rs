@@ -0,0 +1,182 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Automatically apply rustc's machine-applicable suggestions with cargo fix
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_fix")]
+pub struct CargoFix {
+    /// Optional package name to fix (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Fix code even if the working directory has uncommitted changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_dirty: Option<bool>,
+
+    /// Fix code even if the working directory has staged changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_staged: Option<bool>,
+
+    /// Fix code even if a version control system isn't detected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_no_vcs: Option<bool>,
+
+    /// Fix in preparation for the next edition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub edition: Option<bool>,
+
+    /// Fix warnings to migrate to the idioms of the current edition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub edition_idioms: Option<bool>,
+
+    /// Apply fixes even if the code fails to compile afterwards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub broken_code: Option<bool>,
+
+    /// Fix all targets (lib, bins, tests, benches, examples)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_targets: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoFix {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Apply compiler suggestions to a clean working tree",
+                item: Self::default(),
+            },
+            Example {
+                description: "Apply compiler suggestions with uncommitted changes present",
+                item: Self {
+                    allow_dirty: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Fix all targets including tests and examples",
+                item: Self {
+                    all_targets: Some(true),
+                    allow_dirty: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Migrate a package to the next edition",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    edition: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoFix {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["fix"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.allow_dirty.unwrap_or(false) {
+            args.push("--allow-dirty");
+        }
+
+        if self.allow_staged.unwrap_or(false) {
+            args.push("--allow-staged");
+        }
+
+        if self.allow_no_vcs.unwrap_or(false) {
+            args.push("--allow-no-vcs");
+        }
+
+        if self.edition.unwrap_or(false) {
+            args.push("--edition");
+        }
+
+        if self.edition_idioms.unwrap_or(false) {
+            args.push("--edition-idioms");
+        }
+
+        if self.broken_code.unwrap_or(false) {
+            args.push("--broken-code");
+        }
+
+        if self.all_targets.unwrap_or(false) {
+            args.push("--all-targets");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo fix")
+    }
+}
@@ -0,0 +1,239 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+    run_cargo_command_with_interrupt,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Profile a binary, benchmark, test or example with cargo flamegraph and save the
+/// flamegraph as an SVG (requires cargo-flamegraph, and perf on Linux or dtrace on macOS)
+///
+/// The target is built first, then profiling is stopped after max_duration_secs (30 by
+/// default) as if Ctrl-C was pressed, and the flamegraph is written for the samples
+/// collected until then.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_flamegraph")]
+pub struct CargoFlamegraph {
+    /// Optional binary target to profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Optional benchmark target to profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bench: Option<String>,
+
+    /// Optional integration test target to profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test: Option<String>,
+
+    /// Optional example target to profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub example: Option<String>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Arguments to pass to the profiled program after `--` (e.g., ['--bench', 'parse'] for
+    /// a benchmark filter)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub args: Option<Vec<String>>,
+
+    /// Maximum time to profile for in seconds, not counting the build (defaults to 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub max_duration_secs: Option<u64>,
+
+    /// Optional sampling frequency in Hz
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub frequency: Option<u32>,
+
+    /// Optional path for the SVG (defaults to target/flamegraphs/<target>.svg)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub output: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoFlamegraph {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Profile the default binary for up to 30 seconds",
+                item: Self::default(),
+            },
+            Example {
+                description: "Profile one benchmark of a bench target",
+                item: Self {
+                    bench: Some("parser".into()),
+                    args: Some(vec!["--bench".into(), "parse_large".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Profile a binary with arguments for at most 10 seconds",
+                item: Self {
+                    bin: Some("server".into()),
+                    args: Some(vec!["--port".into(), "8080".into()]),
+                    max_duration_secs: Some(10),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoFlamegraph {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("flamegraph", "flamegraph")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let target_name = [&self.bin, &self.bench, &self.test, &self.example]
+            .into_iter()
+            .flatten()
+            .next()
+            .map_or("flamegraph", String::as_str);
+        let svg_path = match self.output {
+            Some(ref output) => project_path.join(output),
+            None => cargo_options
+                .global
+                .target_path(&project_path)
+                .join("flamegraphs")
+                .join(format!("{target_name}.svg")),
+        };
+        if let Some(parent) = svg_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Remove an old graph so a failed run can't be mistaken for a fresh one
+        let _ = std::fs::remove_file(&svg_path);
+        let svg_path_str = svg_path.to_string_lossy().into_owned();
+
+        // Build with the debug info flamegraph enables, so that only profiling counts
+        // against the time limit
+        let mut target_args = Vec::new();
+        for (flag, target) in [
+            ("--bin", &self.bin),
+            ("--bench", &self.bench),
+            ("--test", &self.test),
+            ("--example", &self.example),
+        ] {
+            if let Some(target) = target {
+                target_args.extend_from_slice(&[flag, target.as_str()]);
+            }
+        }
+
+        if let Some(ref package) = self.package {
+            target_args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref features) = features {
+            target_args.extend_from_slice(&["--features", features]);
+        }
+
+        let mut build_env = self.cargo_env.clone().unwrap_or_default();
+        for var in ["CARGO_PROFILE_RELEASE_DEBUG", "CARGO_PROFILE_BENCH_DEBUG"] {
+            build_env.entry(var.into()).or_insert_with(|| "true".into());
+        }
+        let mut build_args = vec!["build", "--release"];
+        build_args.extend_from_slice(&target_args);
+        let build_cmd = create_cargo_command(
+            &build_args,
+            toolchain.as_deref(),
+            Some(&build_env),
+            &cargo_options,
+        );
+        let build_output = run_cargo_command(build_cmd, &project_path)?;
+        if !build_output.status.success() {
+            return Ok(format_cargo_output(
+                &build_output,
+                &project_path,
+                "cargo build",
+            ));
+        }
+
+        let mut args = vec!["flamegraph", "--output", &svg_path_str];
+        args.extend_from_slice(&target_args);
+
+        let frequency_str;
+        if let Some(frequency) = self.frequency {
+            frequency_str = frequency.to_string();
+            args.extend_from_slice(&["--freq", &frequency_str]);
+        }
+
+        if let Some(ref program_args) = self.args
+            && !program_args.is_empty()
+        {
+            args.push("--");
+            args.extend(program_args.iter().map(String::as_str));
+        }
+
+        let max_duration = Duration::from_secs(self.max_duration_secs.unwrap_or(30));
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            Some(&build_env),
+            &cargo_options,
+        );
+        // After the interrupt, give flamegraph time to turn the samples into an SVG
+        let output = run_cargo_command_with_interrupt(
+            cmd,
+            &project_path,
+            max_duration,
+            Duration::from_secs(120),
+        )?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo flamegraph");
+
+        if svg_path.is_file() {
+            result.push_str(&format!("🔥 Flamegraph: {}\n", svg_path.display()));
+        } else {
+            result.push_str("⚠️  No flamegraph was written\n");
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Format code with cargo fmt, or only report formatting violations in check mode
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_fmt")]
+pub struct CargoFmt {
+    /// Only report files that would be reformatted, without modifying them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub check: Option<bool>,
+
+    /// Optional list of packages to format (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<Vec<String>>,
+
+    /// Format all packages in the workspace, including path dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoFmt {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Format the current project",
+                item: Self::default(),
+            },
+            Example {
+                description: "Check formatting without modifying files",
+                item: Self {
+                    check: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Format a single workspace package",
+                item: Self {
+                    package: Some(vec!["my-lib".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Format the whole workspace with nightly rustfmt",
+                item: Self {
+                    all: Some(true),
+                    toolchain: Some("nightly".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoFmt {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["fmt"];
+
+        if self.all.unwrap_or(false) {
+            args.push("--all");
+        }
+
+        for package in self.package.iter().flatten() {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        let command_name = if self.check.unwrap_or(false) {
+            args.push("--check");
+            "cargo fmt --check"
+        } else {
+            "cargo fmt"
+        };
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, command_name)
+    }
+}
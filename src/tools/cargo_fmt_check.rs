@@ -1,4 +1,5 @@
 use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
 use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
 use anyhow::Result;
 use mcplease::{
@@ -12,6 +13,10 @@ use std::collections::HashMap;
 #[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_fmt_check")]
 pub struct CargoFmtCheck {
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -31,6 +36,7 @@ impl WithExamples for CargoFmtCheck {
                 item: Self {
                     toolchain: None,
                     cargo_env: None,
+                    cargo_options: CargoOptions::default(),
                 },
             },
             Example {
@@ -38,6 +44,7 @@ impl WithExamples for CargoFmtCheck {
                 item: Self {
                     toolchain: Some("nightly".into()),
                     cargo_env: None,
+                    cargo_options: CargoOptions::default(),
                 },
             },
         ]
@@ -46,15 +53,23 @@ impl WithExamples for CargoFmtCheck {
 
 impl Tool<CargoTools> for CargoFmtCheck {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
 
         let args = vec!["fmt", "--check"];
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
         execute_cargo_command(cmd, &project_path, "cargo fmt --check")
     }
 }
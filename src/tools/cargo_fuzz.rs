@@ -0,0 +1,166 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Fuzzing runs this long unless told otherwise, so a call can't run forever
+const DEFAULT_MAX_TOTAL_TIME_SECS: u32 = 60;
+
+/// Which cargo fuzz command to run
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FuzzCommand {
+    #[default]
+    List,
+    Run,
+}
+
+/// List and run fuzz targets with cargo fuzz (requires cargo-fuzz), with bounded run time
+///
+/// Provide an `artifact` to reproduce a crash from a previously saved input.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_fuzz")]
+pub struct CargoFuzz {
+    /// Whether to 'list' fuzz targets (the default) or 'run' one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<FuzzCommand>,
+
+    /// Name of the fuzz target to run (required for 'run')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub fuzz_target: Option<String>,
+
+    /// Optional path to a crash artifact to reproduce, e.g.
+    /// 'fuzz/artifacts/my_target/crash-da39a3ee'. Runs the target once on that input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub artifact: Option<String>,
+
+    /// Maximum time to fuzz for, in seconds (defaults to 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub max_total_time: Option<u32>,
+
+    /// Optional maximum number of inputs to try
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub runs: Option<u64>,
+
+    /// Optional number of fuzzing jobs to run in parallel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub jobs: Option<u32>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional nightly toolchain to use (defaults to 'nightly')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoFuzz {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List the available fuzz targets",
+                item: Self::default(),
+            },
+            Example {
+                description: "Fuzz a target for five minutes",
+                item: Self {
+                    command: Some(FuzzCommand::Run),
+                    fuzz_target: Some("parse_input".into()),
+                    max_total_time: Some(300),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Reproduce a crash from a saved artifact",
+                item: Self {
+                    command: Some(FuzzCommand::Run),
+                    fuzz_target: Some("parse_input".into()),
+                    artifact: Some("fuzz/artifacts/parse_input/crash-da39a3ee".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoFuzz {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("fuzz", "cargo-fuzz")?;
+
+        // cargo fuzz needs nightly for sanitizer support
+        let toolchain = self.toolchain.unwrap_or_else(|| "nightly".into());
+
+        let (max_time_arg, runs_arg, jobs_str);
+
+        let args = match self.command.unwrap_or_default() {
+            FuzzCommand::List => vec!["fuzz", "list"],
+            FuzzCommand::Run => {
+                let fuzz_target = self
+                    .fuzz_target
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("fuzz_target is required to run the fuzzer"))?;
+
+                let mut args = vec!["fuzz", "run"];
+
+                if let Some(jobs) = self.jobs {
+                    jobs_str = jobs.to_string();
+                    args.extend_from_slice(&["--jobs", &jobs_str]);
+                }
+
+                args.push(fuzz_target);
+
+                if let Some(ref artifact) = self.artifact {
+                    // Reproducing a crash runs the single input, so no time limit is needed
+                    args.push(artifact);
+                } else {
+                    max_time_arg = format!(
+                        "-max_total_time={}",
+                        self.max_total_time.unwrap_or(DEFAULT_MAX_TOTAL_TIME_SECS)
+                    );
+                    args.extend_from_slice(&["--", &max_time_arg]);
+
+                    if let Some(runs) = self.runs {
+                        runs_arg = format!("-runs={runs}");
+                        args.push(&runs_arg);
+                    }
+                }
+
+                args
+            }
+        };
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(
+            &args,
+            Some(&toolchain),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo fuzz")
+    }
+}
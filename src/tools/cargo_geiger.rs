@@ -0,0 +1,195 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Count unsafe code usage per dependency with cargo geiger (requires cargo-geiger)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_geiger")]
+pub struct CargoGeiger {
+    /// Optional package name to scan (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Only report whether each crate forbids unsafe code, which is much faster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub forbid_only: Option<bool>,
+
+    /// Return the raw JSON report instead of a per-dependency summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub json: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoGeiger {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Count unsafe code used by each dependency",
+                item: Self::default(),
+            },
+            Example {
+                description: "Quickly check which dependencies forbid unsafe code",
+                item: Self {
+                    forbid_only: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoGeiger {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("geiger", "cargo-geiger")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["geiger", "--output-format", "Json"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.forbid_only.unwrap_or(false) {
+            args.push("--forbid-only");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        if !self.json.unwrap_or(false)
+            && let Ok(report) = serde_json::from_str::<Value>(&output.stdout)
+        {
+            output.stdout = summarize_report(&report);
+            // Scanning progress on stderr isn't useful once the report is summarized
+            if output.status.success() {
+                output.stderr.clear();
+            }
+        }
+
+        Ok(format_cargo_output(&output, &project_path, "cargo geiger"))
+    }
+}
+
+/// List dependencies by the amount of unsafe code they use, most unsafe first
+fn summarize_report(report: &Value) -> String {
+    let mut rows: Vec<(String, u64, u64, bool)> = report["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|entry| {
+            let id = &entry["package"]["id"];
+            let name = format!(
+                "{} {}",
+                id["name"].as_str().unwrap_or("?"),
+                id["version"].as_str().unwrap_or("")
+            );
+            let unsafety = &entry["unsafety"];
+            let forbids = unsafety["forbids_unsafe"].as_bool().unwrap_or(false);
+            (
+                name,
+                count_unsafe(&unsafety["used"]),
+                count_unsafe(&unsafety["unused"]),
+                forbids,
+            )
+        })
+        .collect();
+
+    if rows.is_empty() {
+        return "ℹ️  No packages in the report\n".into();
+    }
+
+    rows.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(&b.0)));
+
+    let using_unsafe = rows.iter().filter(|row| row.1 > 0).count();
+    let mut summary = format!(
+        "☢️  {using_unsafe} of {} packages use unsafe code in the build\n",
+        rows.len()
+    );
+    summary.push_str("  used/unused unsafe items, package:\n");
+    for (name, used, unused, forbids) in rows {
+        let marker = if forbids {
+            "🔒"
+        } else if used > 0 {
+            "☢️ "
+        } else {
+            "❓"
+        };
+        summary.push_str(&format!("  {marker} {used}/{unused} {name}\n"));
+    }
+    summary
+        .push_str("\n🔒 forbids unsafe code  ☢️  uses unsafe code  ❓ no forbid, no unsafe used\n");
+
+    summary
+}
+
+/// Sum the unsafe functions, expressions, impls, traits and methods in a geiger counter
+fn count_unsafe(counters: &Value) -> u64 {
+    counters
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(_, counter)| counter["unsafe_"].as_u64())
+        .sum()
+}
@@ -0,0 +1,164 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+/// Create a new project from a template with cargo generate (requires cargo-generate)
+///
+/// Runs without prompting, so template variables must be supplied with `define`.
+/// On success the generated project becomes the working directory for subsequent commands.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_generate")]
+pub struct CargoGenerate {
+    /// Template to use: a git URL, a local path, or a favorite name from the
+    /// cargo-generate config (e.g., 'https://github.com/rust-github/template')
+    pub template: String,
+
+    /// Name of the generated project
+    #[arg(long)]
+    pub name: String,
+
+    /// Optional directory to generate the project in.
+    /// Can be absolute or relative to the current working directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub destination: Option<String>,
+
+    /// Optional template variables as 'key=value' pairs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub define: Option<Vec<String>>,
+
+    /// Optional git branch of the template repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub branch: Option<String>,
+
+    /// Optional git tag of the template repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Optional subfolder of the repository that contains the template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub subfolder: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoGenerate {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Generate a project from a git template",
+                item: Self {
+                    template: "https://github.com/rust-github/template".into(),
+                    name: "my-project".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Generate a project from a favorite, setting template variables",
+                item: Self {
+                    template: "wasm".into(),
+                    name: "my-wasm-lib".into(),
+                    destination: Some("~/code".into()),
+                    define: Some(vec!["authors=Jane Doe".into(), "license=MIT".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoGenerate {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        if let Some(invalid) = self.define.iter().flatten().find(|d| !d.contains('=')) {
+            return Err(anyhow!(
+                "Template variable '{invalid}' must be given as 'key=value'"
+            ));
+        }
+
+        // Projects are generated relative to the current working directory, if any
+        let working_dir = self.cargo_options.working_dir(state)?;
+        ensure_cargo_subcommand("generate", "cargo-generate")?;
+
+        let destination = self
+            .destination
+            .as_deref()
+            .map(|d| working_dir.join(shellexpand::tilde(d).as_ref()))
+            .unwrap_or_else(|| working_dir.clone());
+        let destination_str = destination.to_string_lossy().into_owned();
+
+        let mut args = vec![
+            "generate",
+            "--silent",
+            "--name",
+            &self.name,
+            "--destination",
+            &destination_str,
+        ];
+
+        if let Some(ref branch) = self.branch {
+            args.extend_from_slice(&["--branch", branch]);
+        }
+
+        if let Some(ref tag) = self.tag {
+            args.extend_from_slice(&["--tag", tag]);
+        }
+
+        for define in self.define.iter().flatten() {
+            args.extend_from_slice(&["--define", define]);
+        }
+
+        args.push(&self.template);
+
+        if let Some(ref subfolder) = self.subfolder {
+            args.push(subfolder);
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let output = run_cargo_command(cmd, &working_dir)?;
+        let mut result = format_cargo_output(&output, &working_dir, "cargo generate");
+
+        if output.status.success() {
+            let project_path = generated_path(&output.stderr)
+                .or_else(|| generated_path(&output.stdout))
+                .unwrap_or_else(|| destination.join(&self.name));
+            let project_path = std::fs::canonicalize(project_path)?;
+            state.set_working_directory(project_path.clone(), None)?;
+            result.push_str(&format!(
+                "✅ Working directory set to: {}\n",
+                project_path.display()
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Find the project directory in cargo generate's "Done! New project created <path>" line
+fn generated_path(output: &str) -> Option<PathBuf> {
+    output.lines().find_map(|line| {
+        let (_, path) = line.split_once("New project created")?;
+        Some(PathBuf::from(path.trim()))
+    })
+}
@@ -0,0 +1,174 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which cargo command to run for each feature combination
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HackCommand {
+    #[default]
+    Check,
+    Build,
+    Test,
+    Clippy,
+}
+
+/// Verify that every feature combination compiles with cargo hack (requires cargo-hack)
+///
+/// Checks each feature on its own unless feature_powerset is set.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_hack")]
+pub struct CargoHack {
+    /// Which cargo command to run: 'check' (the default), 'build', 'test' or 'clippy'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<HackCommand>,
+
+    /// Try every combination of features instead of each feature on its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub feature_powerset: Option<bool>,
+
+    /// Optional maximum number of features combined at once (feature_powerset only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub depth: Option<u32>,
+
+    /// Optional features to leave out of the combinations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude_features: Option<Vec<String>>,
+
+    /// Remove dev-dependencies while checking, so they can't hide missing features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_dev_deps: Option<bool>,
+
+    /// Optional packages to check (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<Vec<String>>,
+
+    /// Check all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoHack {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Check each feature on its own",
+                item: Self::default(),
+            },
+            Example {
+                description: "Check all combinations of up to two features without dev-dependencies",
+                item: Self {
+                    feature_powerset: Some(true),
+                    depth: Some(2),
+                    no_dev_deps: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check each feature of every workspace member",
+                item: Self {
+                    workspace: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoHack {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let feature_powerset = self.feature_powerset.unwrap_or(false);
+        if self.depth.is_some() && !feature_powerset {
+            return Err(anyhow!("depth only applies together with feature_powerset"));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("hack", "cargo-hack")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["hack"];
+        args.push(match self.command.unwrap_or_default() {
+            HackCommand::Check => "check",
+            HackCommand::Build => "build",
+            HackCommand::Test => "test",
+            HackCommand::Clippy => "clippy",
+        });
+
+        if feature_powerset {
+            args.push("--feature-powerset");
+        } else {
+            args.push("--each-feature");
+        }
+
+        let depth_str;
+        if let Some(depth) = self.depth {
+            depth_str = depth.to_string();
+            args.extend_from_slice(&["--depth", &depth_str]);
+        }
+
+        let exclude_features;
+        if let Some(ref features) = self.exclude_features {
+            exclude_features = features.join(",");
+            args.extend_from_slice(&["--exclude-features", &exclude_features]);
+        }
+
+        if self.no_dev_deps.unwrap_or(false) {
+            args.push("--no-dev-deps");
+        }
+
+        for package in self.package.iter().flatten() {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo hack")
+    }
+}
@@ -0,0 +1,158 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+/// Create a new cargo package in an existing directory with cargo init
+///
+/// On success the package becomes the working directory for subsequent commands.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_init")]
+pub struct CargoInit {
+    /// Optional directory to initialize (defaults to the current working directory).
+    /// Can be absolute or relative to the current working directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Create a library package instead of a binary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Create a binary package (the default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<bool>,
+
+    /// Optional Rust edition (e.g., '2021', '2024')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub edition: Option<String>,
+
+    /// Optional package name (defaults to the directory name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Optional version control system to initialize ('git', 'hg', 'pijul', 'fossil', 'none')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub vcs: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoInit {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Initialize a binary package in the working directory",
+                item: Self::default(),
+            },
+            Example {
+                description: "Initialize a library package in an existing directory",
+                item: Self {
+                    path: Some("~/projects/existing-code".into()),
+                    lib: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Initialize with a specific name, edition and no version control",
+                item: Self {
+                    name: Some("my-tool".into()),
+                    edition: Some("2021".into()),
+                    vcs: Some("none".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoInit {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let working_dir = self.cargo_options.working_dir(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let path = self
+            .path
+            .as_deref()
+            .map(|path| shellexpand::tilde(path).into_owned());
+
+        let mut args = vec!["init"];
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if self.bin.unwrap_or(false) {
+            args.push("--bin");
+        }
+
+        if let Some(ref edition) = self.edition {
+            args.extend_from_slice(&["--edition", edition]);
+        }
+
+        if let Some(ref name) = self.name {
+            args.extend_from_slice(&["--name", name]);
+        }
+
+        if let Some(ref vcs) = self.vcs {
+            args.extend_from_slice(&["--vcs", vcs]);
+        }
+
+        if let Some(ref path) = path {
+            args.push(path);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &working_dir)?;
+        let mut result = format_cargo_output(&output, &working_dir, "cargo init");
+
+        if output.status.success() {
+            let package_path = match path {
+                Some(path) => std::fs::canonicalize(working_dir.join(PathBuf::from(path)))?,
+                None => working_dir,
+            };
+            state.set_working_directory(package_path.clone(), None)?;
+            result.push_str(&format!(
+                "✅ Working directory set to: {}\n",
+                package_path.display()
+            ));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which cargo insta command to run
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InstaCommand {
+    #[default]
+    Test,
+    Pending,
+    Accept,
+    Reject,
+}
+
+/// Manage insta snapshot tests with cargo insta (requires cargo-insta)
+///
+/// 'test' runs the tests and records new snapshots as pending, 'pending' lists them, and
+/// 'accept' or 'reject' resolves them. Accepting or rejecting requires confirm: true.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_insta")]
+pub struct CargoInsta {
+    /// Which command to run: 'test' (the default), 'pending', 'accept' or 'reject'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<InstaCommand>,
+
+    /// Confirm accepting or rejecting pending snapshots (review the 'pending' output first)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub confirm: Option<bool>,
+
+    /// Optional snapshot name or path, to only accept or reject matching snapshots
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub snapshot: Option<String>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Operate on all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Space-separated list of features to activate (test command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoInsta {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run the snapshot tests",
+                item: Self::default(),
+            },
+            Example {
+                description: "List pending snapshots with their changes",
+                item: Self {
+                    command: Some(InstaCommand::Pending),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Accept all pending snapshots after reviewing them",
+                item: Self {
+                    command: Some(InstaCommand::Accept),
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoInsta {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let command = self.command.unwrap_or_default();
+        let resolves = matches!(command, InstaCommand::Accept | InstaCommand::Reject);
+        if resolves && !self.confirm.unwrap_or(false) {
+            return Err(anyhow!(
+                "Accepting or rejecting snapshots changes files on disk. List them with \
+                 command: 'pending' first, then call again with confirm: true."
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("insta", "cargo-insta")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["insta"];
+        args.push(match command {
+            InstaCommand::Test => "test",
+            InstaCommand::Pending => "pending-snapshots",
+            InstaCommand::Accept => "accept",
+            InstaCommand::Reject => "reject",
+        });
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if let Some(ref snapshot) = self.snapshot
+            && resolves
+        {
+            args.extend_from_slice(&["--snapshot", snapshot]);
+        }
+
+        // New snapshots from 'test' stay pending until they are explicitly accepted
+        if let (InstaCommand::Test, Some(features)) = (command, &features) {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo insta")
+    }
+}
@@ -0,0 +1,202 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::{CargoOptions, GlobalOptions};
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Install a Rust binary (such as a cargo extension) with cargo install
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_install")]
+pub struct CargoInstall {
+    /// Name of the crate to install (e.g., 'cargo-nextest').
+    /// May be omitted when installing from `path` or a single-crate `git` repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub crate_name: Option<String>,
+
+    /// Optional version requirement to install (e.g., '0.9.72' or '^0.9')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub version: Option<String>,
+
+    /// Optional git repository URL to install from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub git: Option<String>,
+
+    /// Optional branch to use when installing from git
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub branch: Option<String>,
+
+    /// Optional tag to use when installing from git
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Optional specific commit to use when installing from git
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rev: Option<String>,
+
+    /// Optional local path to install from (relative to the working directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Force overwriting existing crates or binaries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub force: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoInstall {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Install cargo-nextest with its lockfile",
+                item: Self {
+                    crate_name: Some("cargo-nextest".into()),
+                    cargo_options: CargoOptions {
+                        global: GlobalOptions {
+                            locked: Some(true),
+                            ..GlobalOptions::default()
+                        },
+                        ..CargoOptions::default()
+                    },
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install a pinned version of a tool",
+                item: Self {
+                    crate_name: Some("cargo-expand".into()),
+                    version: Some("1.0.88".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install from a git repository tag",
+                item: Self {
+                    git: Some("https://github.com/rust-lang/rust-clippy".into()),
+                    tag: Some("rust-1.80.0".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install the binary from a local path",
+                item: Self {
+                    path: Some(".".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoInstall {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        if self.crate_name.is_none() && self.git.is_none() && self.path.is_none() {
+            return Err(anyhow!("Specify a crate name, a git repository, or a path"));
+        }
+
+        // Installing doesn't require a Rust project, but paths are relative to it
+        let working_dir = self.cargo_options.working_dir(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["install"];
+
+        if let Some(ref version) = self.version {
+            args.extend_from_slice(&["--version", version]);
+        }
+
+        if let Some(ref git) = self.git {
+            args.extend_from_slice(&["--git", git]);
+        }
+
+        if let Some(ref branch) = self.branch {
+            args.extend_from_slice(&["--branch", branch]);
+        }
+
+        if let Some(ref tag) = self.tag {
+            args.extend_from_slice(&["--tag", tag]);
+        }
+
+        if let Some(ref rev) = self.rev {
+            args.extend_from_slice(&["--rev", rev]);
+        }
+
+        if let Some(ref path) = self.path {
+            args.extend_from_slice(&["--path", path]);
+        }
+
+        if let Some(ref features) = self.features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if self.force.unwrap_or(false) {
+            args.push("--force");
+        }
+
+        if let Some(ref crate_name) = self.crate_name {
+            args.push(crate_name);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &working_dir, "cargo install")
+    }
+}
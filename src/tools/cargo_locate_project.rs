@@ -0,0 +1,115 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// Locate and validate the project for the current working directory
+///
+/// Reports the package manifest, the workspace root and whether the package is the root or
+/// a member, and checks that Cargo.toml parses. Useful to sanity-check the session's path.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_locate_project")]
+pub struct CargoLocateProject {
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+}
+
+impl WithExamples for CargoLocateProject {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Check which project the session points at",
+            item: Self::default(),
+        }]
+    }
+}
+
+impl Tool<CargoTools> for CargoLocateProject {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        // cargo searches parent directories, so this works from anywhere inside a project
+        let working_dir = self.cargo_options.working_dir(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let run = |args: &[&str]| {
+            run_cargo_command(
+                create_cargo_command(args, toolchain.as_deref(), None, &cargo_options),
+                &working_dir,
+            )
+        };
+
+        let mut result = String::from("=== cargo locate-project ===\n");
+        result.push_str(&format!(
+            "📁 Working directory: {}\n\n",
+            working_dir.display()
+        ));
+
+        let package = run(&["locate-project", "--message-format", "plain"])?;
+        if !package.status.success() {
+            result.push_str("❌ No Cargo.toml found in this directory or any parent\n");
+            if !package.stderr.trim().is_empty() {
+                result.push_str(&format!("\n📤 STDERR:\n{}\n", package.stderr.trim_end()));
+            }
+            return Ok(result);
+        }
+        let manifest = package.stdout.trim().to_string();
+
+        let workspace = run(&["locate-project", "--workspace", "--message-format", "plain"])?;
+        let workspace_manifest = workspace.stdout.trim().to_string();
+
+        result.push_str(&format!("📄 Manifest: {manifest}\n"));
+        if workspace.status.success() {
+            result.push_str(&format!("🏢 Workspace manifest: {workspace_manifest}\n"));
+            if Path::new(&manifest) == Path::new(&workspace_manifest) {
+                result.push_str("🧭 This manifest is the workspace root\n");
+            } else {
+                result.push_str("🧭 This manifest is a workspace member\n");
+            }
+        } else {
+            result.push_str(&format!(
+                "⚠️  Could not locate the workspace root:\n{}\n",
+                workspace.stderr.trim_end()
+            ));
+        }
+
+        if Path::new(&manifest).parent() != Some(working_dir.as_path()) {
+            result.push_str(
+                "ℹ️  The working directory is not the package root. Use set_working_directory \
+                 to point the session at the package directly.\n",
+            );
+        }
+
+        // verify-project prints {"success":"true"} or {"invalid":"<reason>"}
+        let verify = run(&["verify-project"])?;
+        let verdict = serde_json::from_str::<Value>(&verify.stdout).unwrap_or_default();
+        if verify.status.success() {
+            result.push_str("✅ Manifest is valid\n");
+        } else if let Some(reason) = verdict["invalid"].as_str() {
+            result.push_str(&format!("❌ Manifest is invalid: {reason}\n"));
+        } else {
+            result.push_str(&format!(
+                "❌ Manifest is invalid:\n{}\n",
+                verify.stderr.trim_end()
+            ));
+        }
+
+        Ok(result)
+    }
+}
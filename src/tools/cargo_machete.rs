@@ -0,0 +1,90 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Quickly find unused dependencies with cargo machete (requires cargo-machete)
+///
+/// Works on stable by searching the source for each dependency's name, so it's faster
+/// than cargo_udeps but can report false positives (e.g., for macro-only crates).
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_machete")]
+pub struct CargoMachete {
+    /// Remove the unused dependencies from Cargo.toml
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub fix: Option<bool>,
+
+    /// Use cargo metadata to resolve renamed crates, which is slower but more accurate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub with_metadata: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoMachete {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Find unused dependencies",
+                item: Self::default(),
+            },
+            Example {
+                description: "Find unused dependencies and remove them from Cargo.toml",
+                item: Self {
+                    fix: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoMachete {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("machete", "cargo-machete")?;
+
+        let mut args = vec!["machete"];
+
+        if self.fix.unwrap_or(false) {
+            args.push("--fix");
+        }
+
+        if self.with_metadata.unwrap_or(false) {
+            args.push("--with-metadata");
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo machete");
+
+        // cargo machete exits with 1 when it finds unused dependencies
+        if output.status.code() == Some(1) {
+            result.push_str(
+                "ℹ️  Unused dependencies were found. If one is only used through macros or \
+                 build scripts, add it to the `package.metadata.cargo-machete.ignored` list.\n",
+            );
+        }
+
+        Ok(result)
+    }
+}
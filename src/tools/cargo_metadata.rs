@@ -0,0 +1,297 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+/// Sections of package metadata that can be included in the output
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataSection {
+    Targets,
+    Features,
+    Dependencies,
+}
+
+/// Get structured project metadata (workspace members, targets, features, dependencies)
+/// from cargo metadata as JSON
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_metadata")]
+pub struct CargoMetadata {
+    /// Only include these packages in the output (defaults to all workspace members)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub packages: Option<Vec<String>>,
+
+    /// Sections to include for each package: 'targets', 'features', 'dependencies'
+    /// (defaults to all of them)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub sections: Option<Vec<MetadataSection>>,
+
+    /// Include resolved non-workspace packages (all dependencies) as well as workspace members.
+    /// This produces much larger output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub include_dependencies: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoMetadata {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Get metadata for all workspace members",
+                item: Self::default(),
+            },
+            Example {
+                description: "List the targets of a specific package",
+                item: Self {
+                    packages: Some(vec!["my-lib".into()]),
+                    sections: Some(vec![MetadataSection::Targets]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the features declared by every workspace member",
+                item: Self {
+                    sections: Some(vec![MetadataSection::Features]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Include metadata about a resolved dependency",
+                item: Self {
+                    packages: Some(vec!["serde".into()]),
+                    include_dependencies: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoMetadata {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let include_dependencies = self.include_dependencies.unwrap_or(false);
+
+        let mut args = vec!["metadata", "--format-version", "1"];
+
+        if !include_dependencies {
+            args.push("--no-deps");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        if output.status.success() {
+            let metadata: Value = serde_json::from_str(&output.stdout)
+                .map_err(|e| anyhow!("Could not parse cargo metadata output: {e}"))?;
+            let filtered = filter_metadata(
+                &metadata,
+                self.packages.as_deref(),
+                self.sections.as_deref(),
+                include_dependencies,
+            );
+            output.stdout = serde_json::to_string_pretty(&filtered)?;
+        }
+
+        Ok(format_cargo_output(
+            &output,
+            &project_path,
+            "cargo metadata",
+        ))
+    }
+}
+
+/// Reduce the raw metadata to the packages and sections that were asked for
+fn filter_metadata(
+    metadata: &Value,
+    names: Option<&[String]>,
+    sections: Option<&[MetadataSection]>,
+    include_dependencies: bool,
+) -> Value {
+    let members: Vec<&str> = metadata["workspace_members"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    let wants = |section| sections.is_none_or(|sections| sections.contains(&section));
+
+    let packages: Vec<Value> = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|package| {
+            include_dependencies
+                || package["id"]
+                    .as_str()
+                    .is_some_and(|id| members.contains(&id))
+        })
+        .filter(|package| {
+            names.is_none_or(|names| {
+                package["name"]
+                    .as_str()
+                    .is_some_and(|name| names.iter().any(|n| n == name))
+            })
+        })
+        .map(|package| {
+            let mut summary = Map::new();
+            for key in [
+                "name",
+                "version",
+                "manifest_path",
+                "edition",
+                "rust_version",
+            ] {
+                if !package[key].is_null() {
+                    summary.insert(key.into(), package[key].clone());
+                }
+            }
+
+            if wants(MetadataSection::Targets) {
+                summary.insert("targets".into(), summarize_targets(&package["targets"]));
+            }
+
+            if wants(MetadataSection::Features) {
+                summary.insert("features".into(), package["features"].clone());
+            }
+
+            if wants(MetadataSection::Dependencies) {
+                summary.insert(
+                    "dependencies".into(),
+                    summarize_dependencies(&package["dependencies"]),
+                );
+            }
+
+            Value::Object(summary)
+        })
+        .collect();
+
+    json!({
+        "workspace_root": metadata["workspace_root"],
+        "target_directory": metadata["target_directory"],
+        "workspace_members": members,
+        "packages": packages,
+    })
+}
+
+fn summarize_targets(targets: &Value) -> Value {
+    targets
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|target| {
+            let mut summary = json!({
+                "name": target["name"],
+                "kind": target["kind"],
+                "src_path": target["src_path"],
+            });
+            if let Some(required) = target["required-features"].as_array()
+                && !required.is_empty()
+            {
+                summary["required_features"] = Value::Array(required.clone());
+            }
+            summary
+        })
+        .collect()
+}
+
+fn summarize_dependencies(dependencies: &Value) -> Value {
+    dependencies
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|dependency| {
+            let mut summary = json!({
+                "name": dependency["name"],
+                "req": dependency["req"],
+                "kind": dependency["kind"].as_str().unwrap_or("normal"),
+            });
+            for key in ["rename", "target"] {
+                if !dependency[key].is_null() {
+                    summary[key] = dependency[key].clone();
+                }
+            }
+            if dependency["optional"].as_bool().unwrap_or(false) {
+                summary["optional"] = Value::Bool(true);
+            }
+            if let Some(features) = dependency["features"].as_array()
+                && !features.is_empty()
+            {
+                summary["features"] = Value::Array(features.clone());
+            }
+            summary
+        })
+        .collect()
+}
@@ -0,0 +1,175 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_rustup_component, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which cargo command to run under Miri
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MiriCommand {
+    #[default]
+    Test,
+    Run,
+}
+
+/// Run tests or a binary under Miri to detect undefined behavior in unsafe code
+///
+/// Uses the nightly toolchain unless another toolchain is given, and installs the
+/// miri component automatically if it is missing.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_miri")]
+pub struct CargoMiri {
+    /// Whether to run 'test' (the default) or 'run' under Miri
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<MiriCommand>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional test name filter (test command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_name: Option<String>,
+
+    /// Optional binary name to run (run command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Arguments to pass to the binary after `--` (run command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub args: Option<Vec<String>>,
+
+    /// Optional flags for Miri, passed through the MIRIFLAGS environment variable
+    /// (e.g., '-Zmiri-disable-isolation -Zmiri-strict-provenance')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub miriflags: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional nightly toolchain to use (defaults to 'nightly')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoMiri {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run all tests under Miri",
+                item: Self::default(),
+            },
+            Example {
+                description: "Run a specific test under Miri",
+                item: Self {
+                    test_name: Some("test_raw_pointer_access".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests with host filesystem access and strict provenance",
+                item: Self {
+                    miriflags: Some("-Zmiri-disable-isolation -Zmiri-strict-provenance".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run a binary under Miri",
+                item: Self {
+                    command: Some(MiriCommand::Run),
+                    bin: Some("my-binary".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoMiri {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Miri is only available on nightly, so don't fall back to the session default
+        let toolchain = self.toolchain.unwrap_or_else(|| "nightly".into());
+        // Miri builds its own sysroot, which needs the standard library sources
+        let mut install_notes = String::new();
+        for component in ["miri", "rust-src"] {
+            if let Some(note) = ensure_rustup_component(component, &toolchain)? {
+                install_notes.push_str(&note);
+            }
+        }
+
+        let command = self.command.unwrap_or_default();
+
+        let mut args = vec!["miri"];
+        args.push(match command {
+            MiriCommand::Test => "test",
+            MiriCommand::Run => "run",
+        });
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        match command {
+            MiriCommand::Test => {
+                if let Some(ref test_name) = self.test_name {
+                    args.push(test_name);
+                }
+            }
+            MiriCommand::Run => {
+                if let Some(ref bin) = self.bin {
+                    args.extend_from_slice(&["--bin", bin]);
+                }
+
+                if let Some(ref binary_args) = self.args
+                    && !binary_args.is_empty()
+                {
+                    args.push("--");
+                    for arg in binary_args {
+                        args.push(arg);
+                    }
+                }
+            }
+        }
+
+        let mut env = self.cargo_env.unwrap_or_default();
+        if let Some(miriflags) = self.miriflags {
+            env.insert("MIRIFLAGS".into(), miriflags);
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, Some(&toolchain), Some(&env), &cargo_options);
+        let result = execute_cargo_command(cmd, &project_path, "cargo miri")?;
+
+        if install_notes.is_empty() {
+            Ok(result)
+        } else {
+            Ok(format!("{install_notes}\n{result}"))
+        }
+    }
+}
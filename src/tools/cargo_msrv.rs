@@ -0,0 +1,204 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, path::PathBuf};
+
+/// Determine the minimum supported Rust version, or verify that the crate builds on the
+/// `rust-version` declared in Cargo.toml
+///
+/// Finding the MSRV bisects toolchains with cargo msrv (requires cargo-msrv). Verifying
+/// only needs rustup, since it runs cargo check on the declared toolchain.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_msrv")]
+pub struct CargoMsrv {
+    /// Verify that the crate builds on its declared rust-version instead of searching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub verify: Option<bool>,
+
+    /// Optional package name whose rust-version to verify (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional lowest version to consider when searching (e.g., '1.60.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub min: Option<String>,
+
+    /// Optional highest version to consider when searching (e.g., '1.80.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub max: Option<String>,
+
+    /// Try versions one by one from newest to oldest instead of bisecting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub linear: Option<bool>,
+
+    /// Write the MSRV that was found to Cargo.toml as rust-version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub write_msrv: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoMsrv {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Find the minimum supported Rust version",
+                item: Self::default(),
+            },
+            Example {
+                description: "Find the MSRV within a range and record it in Cargo.toml",
+                item: Self {
+                    min: Some("1.70.0".into()),
+                    write_msrv: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check that the crate still builds on its declared rust-version",
+                item: Self {
+                    verify: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoMsrv {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        let declared = declared_rust_version(&project_path, self.package.as_deref());
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        if self.verify.unwrap_or(false) {
+            return self.verify(&project_path, declared, &cargo_options);
+        }
+
+        ensure_cargo_subcommand("msrv", "cargo-msrv")?;
+
+        let mut args = vec!["msrv", "find"];
+
+        if let Some(ref min) = self.min {
+            args.extend_from_slice(&["--min", min]);
+        }
+
+        if let Some(ref max) = self.max {
+            args.extend_from_slice(&["--max", max]);
+        }
+
+        if self.linear.unwrap_or(false) {
+            args.push("--linear");
+        }
+
+        if self.write_msrv.unwrap_or(false) {
+            args.push("--write-msrv");
+        }
+
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo msrv find");
+
+        match declared {
+            Some(version) => result.push_str(&format!("📌 Declared rust-version: {version}\n")),
+            None => result.push_str("📌 No rust-version declared in Cargo.toml\n"),
+        }
+
+        Ok(result)
+    }
+}
+
+impl CargoMsrv {
+    fn verify(
+        self,
+        project_path: &PathBuf,
+        declared: Option<String>,
+        cargo_options: &CargoOptions,
+    ) -> Result<String> {
+        let version = declared.ok_or_else(|| {
+            anyhow!("No rust-version declared in Cargo.toml, so there is nothing to verify")
+        })?;
+
+        let mut args = vec!["check"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            Some(&version),
+            self.cargo_env.as_ref(),
+            cargo_options,
+        );
+        let output = run_cargo_command(cmd, project_path)?;
+        let mut result = format_cargo_output(&output, project_path, "cargo msrv verify");
+
+        if output.status.success() {
+            result.push_str(&format!("📌 Builds on declared rust-version {version}\n"));
+        } else {
+            result.push_str(&format!(
+                "📌 Does not build on declared rust-version {version}\n"
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Look up the rust-version of the selected package (or the root package) via cargo metadata
+fn declared_rust_version(project_path: &PathBuf, package: Option<&str>) -> Option<String> {
+    let output = create_cargo_command(
+        &["metadata", "--no-deps", "--format-version", "1"],
+        None,
+        None,
+        &CargoOptions::default(),
+    )
+    .current_dir(project_path)
+    .output()
+    .ok()?;
+    let metadata: Value = serde_json::from_slice(&output.stdout).ok()?;
+    let packages = metadata["packages"].as_array()?;
+
+    let selected = match package {
+        Some(name) => packages.iter().find(|pkg| pkg["name"] == name),
+        // Without dependencies there's no resolve.root, so the root package is the one
+        // whose manifest is the project's
+        None => {
+            let manifest = std::fs::canonicalize(project_path.join("Cargo.toml")).ok();
+            packages
+                .iter()
+                .find(|pkg| {
+                    manifest.as_deref().is_some_and(|manifest| {
+                        pkg["manifest_path"] == manifest.to_string_lossy().as_ref()
+                    })
+                })
+                .or_else(|| packages.first())
+        }
+    }?;
+
+    selected["rust_version"].as_str().map(String::from)
+}
@@ -0,0 +1,178 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output,
+    run_cargo_command_with_timeout,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Run mutation testing with cargo mutants (requires cargo-mutants) and list the mutants
+/// that the test suite failed to catch
+///
+/// Stops after a time budget (600 seconds by default) and reports what was found so far.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_mutants")]
+pub struct CargoMutants {
+    /// Optional source files to mutate, as paths or globs (e.g., 'src/parser.rs')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub file: Option<Vec<String>>,
+
+    /// Optional regex; only mutate functions whose name matches (e.g., 'parse_.*')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub function: Option<String>,
+
+    /// Optional package name to mutate (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// List the mutants that would be tested without running any tests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub list: Option<bool>,
+
+    /// Total time budget in seconds (defaults to 600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub budget_secs: Option<u64>,
+
+    /// Optional timeout in seconds for the tests of a single mutant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_timeout_secs: Option<u64>,
+
+    /// Optional number of mutants to test in parallel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub jobs: Option<u32>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoMutants {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Find mutants in one file that the tests don't catch",
+                item: Self {
+                    file: Some(vec!["src/parser.rs".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "List the mutants of matching functions without testing them",
+                item: Self {
+                    function: Some("parse_.*".into()),
+                    list: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Spend up to half an hour on the whole crate, four mutants at a time",
+                item: Self {
+                    budget_secs: Some(1800),
+                    jobs: Some(4),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoMutants {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("mutants", "cargo-mutants")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let list = self.list.unwrap_or(false);
+        let mut args = vec!["mutants", "--no-shuffle"];
+
+        if list {
+            args.push("--list");
+        }
+
+        for file in self.file.iter().flatten() {
+            args.extend_from_slice(&["--file", file]);
+        }
+
+        if let Some(ref function) = self.function {
+            args.extend_from_slice(&["--re", function]);
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        let test_timeout_str;
+        if let Some(secs) = self.test_timeout_secs {
+            test_timeout_str = secs.to_string();
+            args.extend_from_slice(&["--timeout", &test_timeout_str]);
+        }
+
+        let jobs_str;
+        if let Some(jobs) = self.jobs {
+            jobs_str = jobs.to_string();
+            args.extend_from_slice(&["--jobs", &jobs_str]);
+        }
+
+        let budget = Duration::from_secs(self.budget_secs.unwrap_or(600));
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command_with_timeout(cmd, &project_path, budget)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo mutants");
+
+        if !list {
+            // cargo mutants appends to missed.txt as it goes, so this is accurate even
+            // when the budget ran out
+            let missed = std::fs::read_to_string(project_path.join("mutants.out/missed.txt"))
+                .unwrap_or_default();
+            let missed: Vec<&str> = missed.lines().filter(|l| !l.trim().is_empty()).collect();
+
+            if missed.is_empty() {
+                result.push_str("🧬 No missed mutants\n");
+            } else {
+                result.push_str(&format!(
+                    "🧬 Missed mutants ({}), each needs a test that fails when it's applied:\n",
+                    missed.len()
+                ));
+                for mutant in missed {
+                    result.push_str(&format!("  {mutant}\n"));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+/// Create a new cargo package with cargo new
+///
+/// On success the new package becomes the working directory for subsequent commands.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_new")]
+pub struct CargoNew {
+    /// Path of the package directory to create.
+    /// Can be absolute or relative to the current working directory.
+    pub path: String,
+
+    /// Create a library package instead of a binary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Create a binary package (the default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<bool>,
+
+    /// Optional Rust edition (e.g., '2021', '2024')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub edition: Option<String>,
+
+    /// Optional package name (defaults to the directory name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Optional version control system to initialize ('git', 'hg', 'pijul', 'fossil', 'none')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub vcs: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoNew {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Create a new binary package",
+                item: Self {
+                    path: "~/projects/my-app".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Create a new library package without version control",
+                item: Self {
+                    path: "my-lib".into(),
+                    lib: Some(true),
+                    vcs: Some("none".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Create a package with a specific name and edition",
+                item: Self {
+                    path: "crates/parser".into(),
+                    name: Some("my-parser".into()),
+                    edition: Some("2021".into()),
+                    lib: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoNew {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        // New packages are created relative to the current working directory, if any
+        let working_dir = self.cargo_options.working_dir(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let path = shellexpand::tilde(&self.path).into_owned();
+
+        let mut args = vec!["new"];
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if self.bin.unwrap_or(false) {
+            args.push("--bin");
+        }
+
+        if let Some(ref edition) = self.edition {
+            args.extend_from_slice(&["--edition", edition]);
+        }
+
+        if let Some(ref name) = self.name {
+            args.extend_from_slice(&["--name", name]);
+        }
+
+        if let Some(ref vcs) = self.vcs {
+            args.extend_from_slice(&["--vcs", vcs]);
+        }
+
+        args.push(&path);
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &working_dir)?;
+        let mut result = format_cargo_output(&output, &working_dir, "cargo new");
+
+        if output.status.success() {
+            let package_path = std::fs::canonicalize(working_dir.join(PathBuf::from(&path)))?;
+            state.set_working_directory(package_path.clone(), None)?;
+            result.push_str(&format!(
+                "✅ Working directory set to: {}\n",
+                package_path.display()
+            ));
+        }
+
+        Ok(result)
+    }
+}
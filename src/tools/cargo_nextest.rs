@@ -0,0 +1,216 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    cargo_subcommand_installed, create_cargo_command, execute_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Run tests with cargo-nextest, falling back to cargo test if nextest isn't installed
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_nextest")]
+pub struct CargoNextest {
+    /// Optional package name to test (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Test all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional test name filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_name: Option<String>,
+
+    /// Optional partition to run, as 'count:M/N' or 'hash:M/N' (nextest only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub partition: Option<String>,
+
+    /// Optional number of times to retry failing tests (nextest only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub retries: Option<u32>,
+
+    /// Keep running tests after the first failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_fail_fast: Option<bool>,
+
+    /// Emit machine-readable libtest-compatible JSON on stdout (nextest only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub json: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoNextest {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run all tests with nextest",
+                item: Self::default(),
+            },
+            Example {
+                description: "Run the first of three partitions of the workspace tests",
+                item: Self {
+                    workspace: Some(true),
+                    partition: Some("count:1/3".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Retry flaky tests twice and keep going after failures",
+                item: Self {
+                    retries: Some(2),
+                    no_fail_fast: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run matching tests with machine-readable JSON output",
+                item: Self {
+                    test_name: Some("parser".into()),
+                    json: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoNextest {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let nextest = cargo_subcommand_installed("nextest");
+
+        let mut args = if nextest {
+            vec!["nextest", "run"]
+        } else {
+            vec!["test"]
+        };
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if self.no_fail_fast.unwrap_or(false) {
+            args.push("--no-fail-fast");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        let mut env = self.cargo_env.unwrap_or_default();
+        let mut skipped = vec![];
+        let retries_str;
+
+        if nextest {
+            if let Some(ref partition) = self.partition {
+                args.extend_from_slice(&["--partition", partition]);
+            }
+
+            if let Some(retries) = self.retries {
+                retries_str = retries.to_string();
+                args.extend_from_slice(&["--retries", &retries_str]);
+            }
+
+            if self.json.unwrap_or(false) {
+                args.extend_from_slice(&["--message-format", "libtest-json"]);
+                env.insert("NEXTEST_EXPERIMENTAL_LIBTEST_JSON".into(), "1".into());
+            }
+        } else {
+            if self.partition.is_some() {
+                skipped.push("partition");
+            }
+            if self.retries.is_some() {
+                skipped.push("retries");
+            }
+            if self.json.is_some() {
+                skipped.push("json");
+            }
+        }
+
+        if let Some(ref test_name) = self.test_name {
+            args.push(test_name);
+        }
+
+        let cmd = create_cargo_command(&args, toolchain.as_deref(), Some(&env), &cargo_options);
+
+        let mut result = if nextest {
+            String::from("🏃 Runner: cargo-nextest\n\n")
+        } else {
+            let mut note = String::from("🏃 Runner: cargo test (cargo-nextest is not installed)\n");
+            if !skipped.is_empty() {
+                note.push_str(&format!(
+                    "⚠️  Ignored nextest-only options: {}\n",
+                    skipped.join(", ")
+                ));
+            }
+            note.push('\n');
+            note
+        };
+
+        let command_name = if nextest {
+            "cargo nextest run"
+        } else {
+            "cargo test"
+        };
+        result.push_str(&execute_cargo_command(cmd, &project_path, command_name)?);
+        Ok(result)
+    }
+}
@@ -0,0 +1,399 @@
+use crate::state::CargoTools;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Cargo's own subcommands, which all take its global flags, and clippy, which passes them
+/// on to cargo check. Other external subcommands (e.g., fmt or machete) reject them.
+const TAKES_GLOBAL_FLAGS: &[&str] = &[
+    "add",
+    "bench",
+    "build",
+    "check",
+    "clean",
+    "clippy",
+    "doc",
+    "fetch",
+    "fix",
+    "generate-lockfile",
+    "info",
+    "init",
+    "install",
+    "locate-project",
+    "metadata",
+    "new",
+    "owner",
+    "package",
+    "pkgid",
+    "publish",
+    "remove",
+    "report",
+    "run",
+    "rustc",
+    "rustdoc",
+    "search",
+    "test",
+    "tree",
+    "uninstall",
+    "update",
+    "vendor",
+    "verify-project",
+    "yank",
+];
+
+/// Options accepted by every tool that runs cargo
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+pub struct CargoOptions {
+    /// Optional path to the Cargo.toml (or its directory) to operate on instead of the
+    /// working directory's, relative to the working directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub manifest_path: Option<String>,
+
+    /// Optional config overrides for this call as 'KEY=VALUE' pairs with TOML values
+    /// (e.g., 'build.jobs=2' or 'profile.dev.debug=false'), passed as --config
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub config: Option<Vec<String>>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub global: GlobalOptions,
+
+    /// Environment variables of the session (see set_default_env), filled in from the
+    /// session defaults. Set before the call's own cargo_env, which overrides them.
+    #[serde(skip)]
+    #[arg(skip)]
+    pub session_env: BTreeMap<String, String>,
+}
+
+/// Cargo's global options, which can also be set as session defaults (see
+/// set_cargo_defaults)
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema, clap::Args,
+)]
+pub struct GlobalOptions {
+    /// Run without accessing the network, failing if something has to be downloaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub offline: Option<bool>,
+
+    /// Fail instead of updating Cargo.lock
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub locked: Option<bool>,
+
+    /// Both offline and locked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub frozen: Option<bool>,
+
+    /// Optional directory for build output, relative to the project (e.g., 'target-mcp'),
+    /// to avoid waiting for the build lock of builds running in an IDE or terminal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target_dir: Option<String>,
+
+    /// How much cargo prints. Verbose output shows the rustc invocations, and very verbose
+    /// output also shows what build scripts print.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub verbosity: Option<Verbosity>,
+
+    /// Whether cargo colors its output. Escape sequences are stripped from the output
+    /// unless this is always.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub color: Option<ColorChoice>,
+}
+
+/// How much cargo prints
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    schemars::JsonSchema,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Verbosity {
+    /// No progress output (-q)
+    Quiet,
+    /// Cargo's default
+    #[default]
+    Normal,
+    /// Also show the commands cargo runs (-v)
+    Verbose,
+    /// Also show the output of build scripts (-vv)
+    #[serde(alias = "very-verbose")]
+    VeryVerbose,
+}
+
+/// When cargo colors its output
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    schemars::JsonSchema,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorChoice {
+    /// Color if the output goes to a terminal, which it doesn't here
+    #[default]
+    Auto,
+    /// Always color, keeping the escape sequences in the output
+    Always,
+    /// Never color
+    Never,
+}
+
+impl CargoOptions {
+    /// Fill in the global options not given for this call from the session defaults
+    pub fn with_session_defaults(&self, state: &mut CargoTools) -> Result<Self> {
+        if let Some(invalid) = self.config.iter().flatten().find(|c| !c.contains('=')) {
+            return Err(anyhow!(
+                "Config override '{invalid}' must be given as 'KEY=VALUE', e.g. 'build.jobs=2'"
+            ));
+        }
+
+        Ok(Self {
+            manifest_path: self.manifest_path.clone(),
+            config: self.config.clone(),
+            global: self.global.or(&state.get_default_cargo_options(None)?),
+            session_env: state.get_default_env(None)?,
+        })
+    }
+
+    /// Add the cargo arguments to a command, with the options right after the subcommand
+    /// (e.g., `clippy` or `nextest run`). Options before the subcommand are only understood
+    /// by cargo itself, and not passed on to external subcommands. Config overrides are the
+    /// exception: external subcommands that don't take cargo's flags reject them, so they go
+    /// before the subcommand for cargo itself to handle.
+    pub fn apply(&self, cmd: &mut Command, cargo_args: &[&str]) {
+        let subcommand_len = cargo_args
+            .iter()
+            .position(|arg| arg.starts_with('-'))
+            .unwrap_or(cargo_args.len());
+        let (subcommand, rest) = cargo_args.split_at(subcommand_len);
+        let takes_flags = subcommand
+            .first()
+            .is_some_and(|subcommand| TAKES_GLOBAL_FLAGS.contains(subcommand));
+
+        let configs = self
+            .config
+            .iter()
+            .flatten()
+            .flat_map(|config| ["--config", config]);
+
+        cmd.envs(&self.session_env);
+        if takes_flags {
+            cmd.args(subcommand);
+            self.global.apply(cmd, rest, takes_flags);
+            cmd.args(configs);
+        } else {
+            cmd.args(configs);
+            cmd.args(subcommand);
+            self.global.apply(cmd, rest, takes_flags);
+        }
+        cmd.args(rest);
+    }
+
+    /// The project to run in: the directory of the given manifest, or the working directory
+    pub fn project_path(&self, state: &mut CargoTools) -> Result<PathBuf> {
+        match self.manifest_path {
+            Some(ref manifest_path) => {
+                let base = match state.get_context(None)? {
+                    Some(context) => context,
+                    None => std::env::current_dir()?,
+                };
+                manifest_dir(&base, manifest_path)
+            }
+            None => state.ensure_rust_project(None),
+        }
+    }
+
+    /// Like `project_path`, for tools that don't need a Rust project to run in
+    pub fn working_dir(&self, state: &mut CargoTools) -> Result<PathBuf> {
+        match (&self.manifest_path, state.get_context(None)?) {
+            (Some(_), _) => self.project_path(state),
+            (None, Some(context)) => Ok(context),
+            (None, None) => Ok(std::env::current_dir()?),
+        }
+    }
+}
+
+impl GlobalOptions {
+    /// Fill in the options not given here from `defaults`
+    pub fn or(&self, defaults: &Self) -> Self {
+        Self {
+            offline: self.offline.or(defaults.offline),
+            locked: self.locked.or(defaults.locked),
+            frozen: self.frozen.or(defaults.frozen),
+            target_dir: self
+                .target_dir
+                .clone()
+                .or_else(|| defaults.target_dir.clone())
+                // An empty directory stands for cargo's default
+                .filter(|dir| !dir.is_empty()),
+            verbosity: self.verbosity.or(defaults.verbosity),
+            color: self.color.or(defaults.color),
+        }
+    }
+
+    /// Whether cargo must not access the network
+    pub fn is_offline(&self) -> bool {
+        self.offline.unwrap_or(false) || self.frozen.unwrap_or(false)
+    }
+
+    /// Whether cargo must not update Cargo.lock
+    pub fn is_locked(&self) -> bool {
+        self.locked.unwrap_or(false) || self.frozen.unwrap_or(false)
+    }
+
+    /// Whether escape sequences are kept in the output
+    pub fn keeps_color(&self) -> bool {
+        self.color == Some(ColorChoice::Always)
+    }
+
+    /// Add the options to a command right after the cargo subcommand, leaving out flags the
+    /// tool already gives in `args` (e.g., `--quiet`). `takes_flags` is set for the
+    /// subcommands that take cargo's global flags (see `TAKES_GLOBAL_FLAGS`); other external
+    /// subcommands reject them, and only get being offline and the verbosity from the
+    /// environment, which the cargo processes they spawn read too.
+    pub fn apply(&self, cmd: &mut Command, args: &[&str], takes_flags: bool) {
+        // Arguments after `--` are the program's (e.g., for cargo run), not cargo's
+        let cargo_args = args.split(|arg| *arg == "--").next().unwrap_or_default();
+        let mut flag = |flag: &str| {
+            if !cargo_args.contains(&flag) {
+                cmd.arg(flag);
+            }
+        };
+
+        if takes_flags {
+            if self.frozen.unwrap_or(false) {
+                flag("--frozen");
+            } else {
+                if self.offline.unwrap_or(false) {
+                    flag("--offline");
+                }
+                if self.locked.unwrap_or(false) {
+                    flag("--locked");
+                }
+            }
+        }
+
+        match (self.verbosity.unwrap_or_default(), takes_flags) {
+            (Verbosity::Normal, _) => {}
+            (Verbosity::Quiet, true) => flag("--quiet"),
+            (Verbosity::Verbose, true) => flag("--verbose"),
+            (Verbosity::VeryVerbose, true) => flag("-vv"),
+            (Verbosity::Quiet, false) => {
+                cmd.env("CARGO_TERM_QUIET", "true");
+            }
+            // There's no setting for very verbose
+            (Verbosity::Verbose | Verbosity::VeryVerbose, false) => {
+                cmd.env("CARGO_TERM_VERBOSE", "true");
+            }
+        }
+
+        // Through the environment, since not every external subcommand takes --color. A
+        // --color the tool gives still wins.
+        if let Some(color) = self.color {
+            cmd.env(
+                "CARGO_TERM_COLOR",
+                match color {
+                    ColorChoice::Auto => "auto",
+                    ColorChoice::Always => "always",
+                    ColorChoice::Never => "never",
+                },
+            );
+        }
+
+        if self.is_offline() {
+            cmd.env("CARGO_NET_OFFLINE", "true");
+        }
+
+        // Unlike --target-dir, the environment variable is understood by every subcommand.
+        // Cargo resolves it relative to the directory it runs in, which is the project.
+        if let Some(ref target_dir) = self.target_dir {
+            cmd.env("CARGO_TARGET_DIR", target_dir);
+        }
+    }
+
+    /// The directory build output goes to, for the project cargo runs in
+    pub fn target_path(&self, project_path: &Path) -> PathBuf {
+        project_path.join(self.target_dir.as_deref().unwrap_or("target"))
+    }
+
+    /// Describe the options that are set, e.g. "offline, locked"
+    pub fn describe(&self) -> String {
+        let mut on = Vec::new();
+        if self.is_offline() {
+            on.push("offline");
+        }
+        if self.is_locked() {
+            on.push("locked");
+        }
+        let target_dir = self
+            .target_dir
+            .as_ref()
+            .map(|dir| format!("target dir {dir}"));
+        on.extend(target_dir.as_deref());
+        let verbosity = match self.verbosity.unwrap_or_default() {
+            Verbosity::Quiet => Some("quiet"),
+            Verbosity::Normal => None,
+            Verbosity::Verbose => Some("verbose"),
+            Verbosity::VeryVerbose => Some("very verbose"),
+        };
+        on.extend(verbosity);
+        let color = match self.color {
+            Some(ColorChoice::Auto) => Some("color auto"),
+            Some(ColorChoice::Always) => Some("color always"),
+            Some(ColorChoice::Never) => Some("color never"),
+            None => None,
+        };
+        on.extend(color);
+        if on.is_empty() {
+            "none".to_string()
+        } else {
+            on.join(", ")
+        }
+    }
+}
+
+/// Resolve a manifest path to the directory cargo should run in. Running there instead of
+/// passing --manifest-path also works for external subcommands that don't take it.
+fn manifest_dir(base: &Path, manifest_path: &str) -> Result<PathBuf> {
+    let path = base.join(&*shellexpand::tilde(manifest_path));
+    let manifest = if path.is_dir() {
+        path.join("Cargo.toml")
+    } else {
+        path
+    };
+
+    if manifest.file_name().is_none_or(|name| name != "Cargo.toml") {
+        return Err(anyhow!(
+            "The manifest path must point to a Cargo.toml file: {}",
+            manifest.display()
+        ));
+    }
+    let manifest = std::fs::canonicalize(&manifest)
+        .map_err(|e| anyhow!("Could not find manifest '{}': {e}", manifest.display()))?;
+
+    Ok(manifest.parent().map(Path::to_path_buf).unwrap_or_default())
+}
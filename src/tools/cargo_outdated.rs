@@ -0,0 +1,184 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Report dependencies with newer compatible or incompatible versions using cargo outdated
+/// (requires cargo-outdated)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_outdated")]
+pub struct CargoOutdated {
+    /// Check every workspace member, reporting dependencies per member
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional package name to check (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Only check direct dependencies, not transitive ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub root_deps_only: Option<bool>,
+
+    /// Dependencies to leave out of the report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude: Option<Vec<String>>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoOutdated {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Report outdated direct dependencies",
+                item: Self {
+                    root_deps_only: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Report outdated dependencies for every workspace member",
+                item: Self {
+                    workspace: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check a single package, ignoring a known-pinned dependency",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    exclude: Some(vec!["rand".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoOutdated {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("outdated", "cargo-outdated")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["outdated", "--format", "json"];
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.root_deps_only.unwrap_or(false) {
+            args.push("--root-deps-only");
+        }
+
+        let exclude_str;
+        if let Some(ref exclude) = self.exclude
+            && !exclude.is_empty()
+        {
+            exclude_str = exclude.join(",");
+            args.extend_from_slice(&["--exclude", &exclude_str]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        if output.status.success() {
+            output.stdout = serde_json::to_string_pretty(&summarize_report(&output.stdout))?;
+        }
+
+        Ok(format_cargo_output(
+            &output,
+            &project_path,
+            "cargo outdated",
+        ))
+    }
+}
+
+/// cargo outdated prints one JSON object per workspace member. Group each member's
+/// dependencies by whether a semver-compatible update exists or only a breaking one.
+fn summarize_report(stdout: &str) -> Value {
+    let available = |version: &Value| {
+        version
+            .as_str()
+            .filter(|v| *v != "---" && *v != "Removed")
+            .map(str::to_string)
+    };
+
+    let members: Vec<Value> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .map(|member| {
+            let mut compatible = vec![];
+            let mut incompatible = vec![];
+
+            for dependency in member["dependencies"].as_array().into_iter().flatten() {
+                let project = available(&dependency["project"]);
+                let compat = available(&dependency["compat"]);
+                let latest = available(&dependency["latest"]);
+                let entry = json!({
+                    "name": dependency["name"],
+                    "current": project,
+                    "compatible": compat,
+                    "latest": latest,
+                    "kind": dependency["kind"],
+                });
+
+                if compat.is_some() && compat != project {
+                    compatible.push(entry);
+                } else if latest.is_some() && latest != project {
+                    incompatible.push(entry);
+                }
+            }
+
+            json!({
+                "member": member["crate_name"],
+                "compatible_updates": compatible,
+                "incompatible_updates": incompatible,
+            })
+        })
+        .collect();
+
+    Value::Array(members)
+}
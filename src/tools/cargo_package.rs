@@ -0,0 +1,159 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Assemble the package into a distributable tarball with cargo package
+///
+/// Use `list` to see which files would be included in a crates.io release, or run
+/// without it to verify that the package builds from the generated tarball.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_package")]
+pub struct CargoPackage {
+    /// Optional package name to package (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Only list the files that would be included in the package
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub list: Option<bool>,
+
+    /// Allow packaging with uncommitted changes in the working directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_dirty: Option<bool>,
+
+    /// Don't verify the contents by building them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_verify: Option<bool>,
+
+    /// Space-separated list of features to activate when verifying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features when verifying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoPackage {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List the files that would be published",
+                item: Self {
+                    list: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Verify that the package builds from the generated tarball",
+                item: Self::default(),
+            },
+            Example {
+                description: "Verify a specific workspace package with uncommitted changes",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    allow_dirty: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoPackage {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let list = self.list.unwrap_or(false);
+
+        let mut args = vec!["package"];
+
+        if list {
+            args.push("--list");
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.allow_dirty.unwrap_or(false) {
+            args.push("--allow-dirty");
+        }
+
+        if self.no_verify.unwrap_or(false) {
+            args.push("--no-verify");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        let command_name = if list {
+            "cargo package --list"
+        } else {
+            "cargo package"
+        };
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, command_name);
+
+        if list && output.status.success() {
+            let count = output.stdout.lines().filter(|l| !l.is_empty()).count();
+            result.push_str(&format!(
+                "📦 {count} files would be included in the package\n"
+            ));
+        }
+
+        Ok(result)
+    }
+}
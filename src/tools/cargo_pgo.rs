@@ -0,0 +1,404 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    CargoOutput, create_cargo_command, ensure_rustup_component, format_cargo_output, human_size,
+    run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Which workload to profile
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PgoWorkload {
+    #[default]
+    Test,
+    Bench,
+}
+
+/// Optimize a release build with profile-guided optimization
+///
+/// Builds and times a baseline, builds with -Cprofile-generate, runs the workload (tests or
+/// benchmarks) to collect profiles, merges them with llvm-profdata, and rebuilds with
+/// -Cprofile-use. Reports binary sizes and workload timings before and after. The workload
+/// should exercise the code paths that matter in production. Installs the llvm-tools
+/// component if it is missing.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_pgo")]
+pub struct CargoPgo {
+    /// Which workload to profile with: 'test' (the default) or 'bench'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workload: Option<PgoWorkload>,
+
+    /// Optional test or benchmark name filter for the workload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workload_filter: Option<String>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoPgo {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Optimize using the test suite as the workload",
+                item: Self::default(),
+            },
+            Example {
+                description: "Optimize using the parser benchmarks as the workload",
+                item: Self {
+                    workload: Some(PgoWorkload::Bench),
+                    workload_filter: Some("parse".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoPgo {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut report = String::from("=== cargo pgo ===\n");
+        report.push_str(&format!(
+            "📁 Working directory: {}\n",
+            project_path.display()
+        ));
+        let component_toolchain = toolchain
+            .clone()
+            .unwrap_or_else(|| active_toolchain(&project_path));
+        if let Some(note) = ensure_rustup_component("llvm-tools", &component_toolchain)? {
+            report.push_str(&note);
+        }
+        report.push('\n');
+
+        let target_path = cargo_options.global.target_path(&project_path);
+        let profile_dir = target_path.join("pgo-profiles");
+        // Stale profiles from an earlier run would skew the merged profile
+        if profile_dir.exists() {
+            std::fs::remove_dir_all(&profile_dir)?;
+        }
+        std::fs::create_dir_all(&profile_dir)?;
+        let merged = profile_dir.join("merged.profdata");
+
+        let mut pgo = Pgo {
+            project_path: &project_path,
+            toolchain: toolchain.as_deref(),
+            env: self.cargo_env.unwrap_or_default(),
+            cargo_options: &cargo_options,
+            package: self.package.as_deref(),
+            features: features.as_deref(),
+            workload: self.workload.unwrap_or_default(),
+            workload_filter: self.workload_filter.as_deref(),
+            report,
+        };
+
+        let generate = format!("-Cprofile-generate={}", profile_dir.display());
+        let use_profile = format!("-Cprofile-use={}", merged.display());
+
+        let Some(baseline) = pgo.build("Baseline build", None)? else {
+            return Ok(pgo.report);
+        };
+        let Some(baseline_time) = pgo.workload("Baseline workload", None)? else {
+            return Ok(pgo.report);
+        };
+        if pgo.build("Instrumented build", Some(&generate))?.is_none()
+            || pgo
+                .workload("Profiling workload", Some(&generate))?
+                .is_none()
+            || !pgo.merge_profiles(&profile_dir, &merged)?
+        {
+            return Ok(pgo.report);
+        }
+        let Some(optimized) = pgo.build("Optimized build", Some(&use_profile))? else {
+            return Ok(pgo.report);
+        };
+        let Some(optimized_time) = pgo.workload("Optimized workload", Some(&use_profile))? else {
+            return Ok(pgo.report);
+        };
+
+        let mut report = pgo.report;
+        if !optimized.is_empty() {
+            report.push_str("\n📏 Binary sizes (baseline → optimized):\n");
+            for (name, size) in &optimized {
+                match baseline
+                    .iter()
+                    .find(|(baseline_name, _)| baseline_name == name)
+                {
+                    Some((_, before)) => report.push_str(&format!(
+                        "  {name}: {} → {}\n",
+                        human_size(*before),
+                        human_size(*size)
+                    )),
+                    None => report.push_str(&format!("  {name}: {}\n", human_size(*size))),
+                }
+            }
+        }
+
+        let before = baseline_time.as_secs_f64();
+        let after = optimized_time.as_secs_f64();
+        report.push_str(&format!(
+            "\n⏱️  Workload time: {before:.2}s → {after:.2}s ({:+.1}%)\n",
+            (after - before) / before.max(f64::EPSILON) * 100.0
+        ));
+        report.push_str(&format!(
+            "📦 The optimized build is in {}\n",
+            target_path.join("release").display()
+        ));
+
+        Ok(report)
+    }
+}
+
+/// State shared by the steps of the workflow, which append to one report
+struct Pgo<'a> {
+    project_path: &'a PathBuf,
+    toolchain: Option<&'a str>,
+    env: HashMap<String, String>,
+    cargo_options: &'a CargoOptions,
+    package: Option<&'a str>,
+    features: Option<&'a str>,
+    workload: PgoWorkload,
+    workload_filter: Option<&'a str>,
+    report: String,
+}
+
+impl<'a> Pgo<'a> {
+    /// Run one cargo command with the given profile flag added to RUSTFLAGS. Returns the
+    /// output and duration, or None after adding the failure to the report.
+    fn step(
+        &mut self,
+        label: &str,
+        args: &[&str],
+        profile_flag: Option<&str>,
+    ) -> Result<Option<(CargoOutput, Duration)>> {
+        let mut env = self.env.clone();
+        if let Some(flag) = profile_flag {
+            let rustflags = match env.get("RUSTFLAGS") {
+                Some(existing) => format!("{existing} {flag}"),
+                None => flag.to_string(),
+            };
+            env.insert("RUSTFLAGS".into(), rustflags);
+        }
+
+        let cmd = create_cargo_command(args, self.toolchain, Some(&env), self.cargo_options);
+        let started = Instant::now();
+        let output = run_cargo_command(cmd, self.project_path)?;
+        let elapsed = started.elapsed();
+
+        if output.status.success() {
+            self.report
+                .push_str(&format!("✅ {label} ({:.2}s)\n", elapsed.as_secs_f64()));
+            Ok(Some((output, elapsed)))
+        } else {
+            self.report.push_str(&format!("❌ {label} failed\n\n"));
+            self.report
+                .push_str(&format_cargo_output(&output, self.project_path, label));
+            Ok(None)
+        }
+    }
+
+    /// Build in release mode, returning the size of each executable that was built
+    fn build(
+        &mut self,
+        label: &str,
+        profile_flag: Option<&str>,
+    ) -> Result<Option<Vec<(String, u64)>>> {
+        let mut args = vec![
+            "build",
+            "--release",
+            "--message-format",
+            "json-render-diagnostics",
+        ];
+        self.push_selection(&mut args);
+
+        let Some((output, _)) = self.step(label, &args, profile_flag)? else {
+            return Ok(None);
+        };
+
+        // Sizes are read right away, since the next build overwrites the binaries
+        let sizes = output
+            .stdout
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter_map(|message| message["executable"].as_str().map(PathBuf::from))
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                Some((name, std::fs::metadata(&path).ok()?.len()))
+            })
+            .collect();
+
+        Ok(Some(sizes))
+    }
+
+    /// Build the workload, then time running it
+    fn workload(&mut self, label: &str, profile_flag: Option<&str>) -> Result<Option<Duration>> {
+        let mut args = match self.workload {
+            PgoWorkload::Test => vec!["test", "--release"],
+            PgoWorkload::Bench => vec!["bench"],
+        };
+        self.push_selection(&mut args);
+
+        let mut build_args = args.clone();
+        build_args.push("--no-run");
+        if self
+            .step(&format!("{label}: build"), &build_args, profile_flag)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        if let Some(filter) = self.workload_filter {
+            args.push(filter);
+        }
+        Ok(self
+            .step(&format!("{label}: run"), &args, profile_flag)?
+            .map(|(_, elapsed)| elapsed))
+    }
+
+    /// Merge the raw profiles with the llvm-profdata that ships with the toolchain
+    fn merge_profiles(&mut self, profile_dir: &Path, merged: &Path) -> Result<bool> {
+        let profdata = llvm_profdata(self.toolchain)?;
+        let output = Command::new(&profdata)
+            .arg("merge")
+            .arg("-o")
+            .arg(merged)
+            .arg(profile_dir)
+            .output()?;
+
+        let profiles = std::fs::read_dir(profile_dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "profraw"))
+            .count();
+
+        if output.status.success() && profiles > 0 {
+            self.report.push_str(&format!(
+                "✅ Merged {profiles} profiles into {}\n",
+                merged.display()
+            ));
+            Ok(true)
+        } else if profiles == 0 {
+            self.report.push_str(
+                "❌ The workload didn't produce any profiles, so there is nothing to merge\n",
+            );
+            Ok(false)
+        } else {
+            self.report.push_str(&format!(
+                "❌ Merging profiles failed:\n{}\n",
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            ));
+            Ok(false)
+        }
+    }
+
+    fn push_selection(&self, args: &mut Vec<&'a str>) {
+        if let Some(package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+        if let Some(features) = self.features {
+            args.extend_from_slice(&["--features", features]);
+        }
+    }
+}
+
+/// Locate llvm-profdata in the toolchain's sysroot, where the llvm-tools component puts it
+fn llvm_profdata(toolchain: Option<&str>) -> Result<PathBuf> {
+    let rustc = |args: &[&str]| -> Result<String> {
+        let mut cmd = match toolchain {
+            Some(toolchain) => {
+                let mut cmd = Command::new("rustup");
+                cmd.args(["run", toolchain, "rustc"]);
+                cmd
+            }
+            None => Command::new("rustc"),
+        };
+        let output = cmd.args(args).output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let sysroot = rustc(&["--print", "sysroot"])?;
+    let version = rustc(&["-vV"])?;
+    let host = version
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .ok_or_else(|| anyhow!("Could not determine the host target from rustc -vV"))?;
+
+    let path = Path::new(sysroot.trim())
+        .join("lib/rustlib")
+        .join(host)
+        .join("bin")
+        .join(format!("llvm-profdata{}", std::env::consts::EXE_SUFFIX));
+
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err(anyhow!("llvm-profdata not found at {}", path.display()))
+    }
+}
+
+/// The toolchain rustup would use in the project directory, for installing components
+fn active_toolchain(project_path: &Path) -> String {
+    Command::new("rustup")
+        .args(["show", "active-toolchain"])
+        .current_dir(project_path)
+        .output()
+        .ok()
+        .and_then(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .split_whitespace()
+                .next()
+                .map(String::from)
+        })
+        .unwrap_or_else(|| "stable".into())
+}
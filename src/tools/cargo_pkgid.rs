@@ -0,0 +1,94 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Resolve a package name (optionally with a version, e.g. 'serde@1.0.200') to its fully
+/// qualified package ID spec with cargo pkgid
+///
+/// Use the result with update, tree or clean when a name matches several packages in the
+/// dependency graph. If the spec is ambiguous, cargo lists the candidates.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_pkgid")]
+pub struct CargoPkgid {
+    /// Optional package spec to resolve (e.g., 'serde', 'serde@1.0.200'). Defaults to the
+    /// current package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub spec: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoPkgid {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show the package ID of the current package",
+                item: Self::default(),
+            },
+            Example {
+                description: "Resolve one of several versions of a dependency",
+                item: Self {
+                    spec: Some("syn@2".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoPkgid {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["pkgid"];
+
+        if let Some(ref spec) = self.spec {
+            args.push(spec);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo pkgid");
+
+        if output.status.success()
+            && let Some(pkgid) = output.stdout.lines().next()
+        {
+            result.push_str(&format!("🆔 Package ID spec: {}\n", pkgid.trim()));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Publish a package to the registry with cargo publish
+///
+/// Runs `cargo publish --dry-run` unless `confirm` is true. Actually publishing
+/// also requires the server to be started with CARGO_MCP_ALLOW_PUBLISH=true.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_publish")]
+pub struct CargoPublish {
+    /// Optional package name to publish (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Actually upload the package. Without this, only a dry run is performed.
+    /// Publishing is permanent: a version can never be overwritten or deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub confirm: Option<bool>,
+
+    /// Optional registry to publish to (defaults to crates.io)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub registry: Option<String>,
+
+    /// Allow publishing with uncommitted changes in the working directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub allow_dirty: Option<bool>,
+
+    /// Don't verify the package by building it before publishing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_verify: Option<bool>,
+
+    /// Space-separated list of features to activate when verifying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features when verifying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoPublish {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Check that the package is ready to publish (dry run)",
+                item: Self::default(),
+            },
+            Example {
+                description: "Dry run publishing a specific workspace package",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Actually publish the package to crates.io",
+                item: Self {
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoPublish {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        let confirm = self.confirm.unwrap_or(false);
+        if confirm && !state.allow_publish() {
+            return Err(anyhow!(
+                "Publishing is disabled on this server. Restart it with \
+                 CARGO_MCP_ALLOW_PUBLISH=true to allow cargo_publish with confirm: true."
+            ));
+        }
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["publish"];
+
+        if !confirm {
+            args.push("--dry-run");
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref registry) = self.registry {
+            args.extend_from_slice(&["--registry", registry]);
+        }
+
+        if self.allow_dirty.unwrap_or(false) {
+            args.push("--allow-dirty");
+        }
+
+        if self.no_verify.unwrap_or(false) {
+            args.push("--no-verify");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        let command_name = if confirm {
+            "cargo publish"
+        } else {
+            "cargo publish --dry-run"
+        };
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, command_name)
+    }
+}
@@ -0,0 +1,189 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, execute_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Release a version with cargo release (requires cargo-release): bump the version, commit,
+/// tag, publish and push
+///
+/// Performs a dry run unless `confirm` is true. Publishing and pushing for real also need
+/// to be allowed on the server with CARGO_MCP_RELEASE_ALLOWLIST (e.g., 'publish,push');
+/// otherwise pass publish: false and push: false to only bump, commit and tag locally.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_release")]
+pub struct CargoRelease {
+    /// Version bump: 'major', 'minor', 'patch', 'release', 'rc', 'beta', 'alpha', or an
+    /// explicit version like '1.2.0'. Without it, releases the current version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub level: Option<String>,
+
+    /// Optional packages to release (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<Vec<String>>,
+
+    /// Release all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Actually perform the release. Without this, only a dry run is performed.
+    /// Published versions are permanent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub confirm: Option<bool>,
+
+    /// Publish to the registry (defaults to true; requires 'publish' in the server allowlist)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub publish: Option<bool>,
+
+    /// Push the commit and tags to the remote (defaults to true; requires 'push' in the
+    /// server allowlist)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub push: Option<bool>,
+
+    /// Create a git tag for the release (defaults to true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub tag: Option<bool>,
+
+    /// Optional registry to publish to (defaults to crates.io)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub registry: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoRelease {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Dry run of a patch release",
+                item: Self {
+                    level: Some("patch".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Bump, commit and tag a minor release locally without publishing",
+                item: Self {
+                    level: Some("minor".into()),
+                    publish: Some(false),
+                    push: Some(false),
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Release a patch version of the whole workspace",
+                item: Self {
+                    level: Some("patch".into()),
+                    workspace: Some(true),
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoRelease {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let confirm = self.confirm.unwrap_or(false);
+        let publish = self.publish.unwrap_or(true);
+        let push = self.push.unwrap_or(true);
+
+        if confirm {
+            let allowlist = state.release_allowlist();
+            let blocked: Vec<&str> = [("publish", publish), ("push", push)]
+                .into_iter()
+                .filter(|(step, wanted)| *wanted && !allowlist.iter().any(|a| a == step))
+                .map(|(step, _)| step)
+                .collect();
+            if !blocked.is_empty() {
+                let steps = if blocked.len() == 1 {
+                    format!("{} step is", blocked[0])
+                } else {
+                    format!("{} steps are", blocked.join(" and "))
+                };
+                let opt_out: Vec<String> = blocked
+                    .iter()
+                    .map(|step| format!("{step}: false"))
+                    .collect();
+                return Err(anyhow!(
+                    "The {steps} not allowed on this server. Add it to \
+                     CARGO_MCP_RELEASE_ALLOWLIST (e.g., 'publish,push') and restart the \
+                     server, or call again with {}.",
+                    opt_out.join(" and ")
+                ));
+            }
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("release", "cargo-release")?;
+
+        let mut args = vec!["release"];
+
+        if let Some(ref level) = self.level {
+            args.push(level);
+        }
+
+        for package in self.package.iter().flatten() {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if !publish {
+            args.push("--no-publish");
+        }
+
+        if !push {
+            args.push("--no-push");
+        }
+
+        if !self.tag.unwrap_or(true) {
+            args.push("--no-tag");
+        }
+
+        if let Some(ref registry) = self.registry {
+            args.extend_from_slice(&["--registry", registry]);
+        }
+
+        // Confirmation happened through the tool call, and there's no terminal to prompt on
+        if confirm {
+            args.extend_from_slice(&["--execute", "--no-confirm"]);
+        }
+
+        let command_name = if confirm {
+            "cargo release"
+        } else {
+            "cargo release (dry run)"
+        };
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        execute_cargo_command(cmd, &project_path, command_name)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
 use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
-use anyhow::{anyhow, Result};
+use anyhow::{Result, anyhow};
 use mcplease::{
     traits::{Tool, WithExamples},
     types::Example,
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Remove dependencies from Cargo.toml using cargo remove
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_remove")]
 pub struct CargoRemove {
     /// List of dependencies to remove
@@ -25,6 +26,15 @@ pub struct CargoRemove {
     #[arg(long)]
     pub dev: Option<bool>,
 
+    /// Remove from build dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub build: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -43,30 +53,38 @@ impl WithExamples for CargoRemove {
                 description: "Remove a dependency",
                 item: Self {
                     dependencies: vec!["unused-crate".into()],
-                    package: None,
-                    dev: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Remove multiple dependencies",
                 item: Self {
                     dependencies: vec!["old-lib".into(), "deprecated-crate".into()],
-                    package: None,
-                    dev: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Remove a dev dependency",
                 item: Self {
                     dependencies: vec!["old-test-util".into()],
-                    package: None,
                     dev: Some(true),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Remove a build dependency",
+                item: Self {
+                    dependencies: vec!["cc".into()],
+                    build: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Remove a dependency from a specific workspace package",
+                item: Self {
+                    dependencies: vec!["serde".into()],
+                    package: Some("my-lib".into()),
+                    ..Self::default()
                 },
             },
         ]
@@ -79,15 +97,18 @@ impl Tool<CargoTools> for CargoRemove {
             return Err(anyhow!("No dependencies specified"));
         }
 
-        let project_path = state.ensure_rust_project(None)?;
-        
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
 
         let mut args = vec!["remove"];
-        
+
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
@@ -96,12 +117,21 @@ impl Tool<CargoTools> for CargoRemove {
             args.push("--dev");
         }
 
+        if self.build.unwrap_or(false) {
+            args.push("--build");
+        }
+
         // Add the dependencies to remove
         for dep in &self.dependencies {
             args.push(dep);
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
         execute_cargo_command(cmd, &project_path, "cargo remove")
     }
 }
@@ -1,6 +1,7 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
-use anyhow::Result;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::{Result, anyhow};
 use mcplease::traits::{Tool, WithExamples};
 use mcplease::types::Example;
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,11 @@ pub struct CargoRun {
     #[arg(long)]
     pub release: Option<bool>,
 
+    /// Optional custom profile to build with (e.g., 'release-lto'); can't be combined with release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+
     /// Space-separated list of features to activate
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -45,11 +51,21 @@ pub struct CargoRun {
     #[arg(long)]
     pub no_default_features: Option<bool>,
 
+    /// Suppress cargo's own build status output so that STDERR only contains
+    /// what the program itself wrote (compile errors are still shown)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub quiet: Option<bool>,
+
     /// Arguments to pass to the binary after `--`
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
     pub args: Option<Vec<String>>,
 
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -89,6 +105,13 @@ impl WithExamples for CargoRun {
                     ..Self::default()
                 },
             },
+            Example {
+                description: "Run quietly so only the program's own output is shown",
+                item: Self {
+                    quiet: Some(true),
+                    ..Self::default()
+                },
+            },
             Example {
                 description: "Run in release mode with specific features",
                 item: Self {
@@ -97,6 +120,13 @@ impl WithExamples for CargoRun {
                     ..Self::default()
                 },
             },
+            Example {
+                description: "Run with a custom profile from Cargo.toml",
+                item: Self {
+                    profile: Some("dev-opt".into()),
+                    ..Self::default()
+                },
+            },
             Example {
                 description: "Run a binary from a specific workspace package",
                 item: Self {
@@ -112,15 +142,42 @@ impl WithExamples for CargoRun {
 
 impl Tool<CargoTools> for CargoRun {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
+        if self.release.unwrap_or(false) && self.profile.is_some() {
+            return Err(anyhow!(
+                "release and profile can't be combined; use profile: 'release' instead"
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
 
         // Use toolchain from args, session default, or none
         let toolchain = self
             .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Use profile from args, session default, or none. Asking for release or not
+        // overrides the default too.
+        let profile = match self.release {
+            Some(_) => self.profile,
+            None => self
+                .profile
+                .or_else(|| state.get_default_profile(None).unwrap_or(None)),
+        };
+
         let mut args = vec!["run"];
 
+        if self.quiet.unwrap_or(false) {
+            args.push("--quiet");
+        }
+
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
@@ -137,7 +194,11 @@ impl Tool<CargoTools> for CargoRun {
             args.push("--release");
         }
 
-        if let Some(ref features) = self.features {
+        if let Some(ref profile) = profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
             args.extend_from_slice(&["--features", features]);
         }
 
@@ -159,7 +220,20 @@ impl Tool<CargoTools> for CargoRun {
             }
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo run")
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo run");
+
+        match output.status.code() {
+            Some(code) => result.push_str(&format!("🔚 Exit code: {code}\n")),
+            None => result.push_str("🔚 Process was terminated by a signal\n"),
+        }
+
+        Ok(result)
     }
 }
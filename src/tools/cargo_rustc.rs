@@ -0,0 +1,198 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Compile a single target of a package with extra flags passed to rustc
+///
+/// Only the selected target gets the extra flags; dependencies are built normally.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_rustc")]
+pub struct CargoRustc {
+    /// Flags passed to rustc after `--` (e.g., ['-C', 'target-cpu=native', '--emit=asm'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rustc_args: Option<Vec<String>>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Compile the library target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Optional binary target to compile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Optional example target to compile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub example: Option<String>,
+
+    /// Optional integration test target to compile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test: Option<String>,
+
+    /// Optional benchmark target to compile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bench: Option<String>,
+
+    /// Build in release mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Optional target triple to compile for (e.g., 'wasm32-unknown-unknown')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Optional crate type to build the library as (e.g., 'cdylib', 'staticlib')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub crate_type: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoRustc {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Build the library for the host CPU",
+                item: Self {
+                    lib: Some(true),
+                    release: Some(true),
+                    rustc_args: Some(vec!["-C".into(), "target-cpu=native".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Emit LLVM IR for a binary",
+                item: Self {
+                    bin: Some("my-app".into()),
+                    rustc_args: Some(vec!["--emit=llvm-ir".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Print type sizes with a nightly-only flag",
+                item: Self {
+                    lib: Some(true),
+                    toolchain: Some("nightly".into()),
+                    rustc_args: Some(vec!["-Zprint-type-sizes".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoRustc {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Without release given, build with the session's default profile, if any
+        let default_profile = match self.release {
+            Some(_) => None,
+            None => state.get_default_profile(None)?,
+        };
+
+        let mut args = vec!["rustc"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        for (flag, target) in [
+            ("--bin", &self.bin),
+            ("--example", &self.example),
+            ("--test", &self.test),
+            ("--bench", &self.bench),
+        ] {
+            if let Some(target) = target {
+                args.extend_from_slice(&[flag, target]);
+            }
+        }
+
+        if self.release.unwrap_or(false) {
+            args.push("--release");
+        }
+
+        if let Some(ref profile) = default_profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        if let Some(ref crate_type) = self.crate_type {
+            args.extend_from_slice(&["--crate-type", crate_type]);
+        }
+
+        if let Some(ref rustc_args) = self.rustc_args {
+            args.push("--");
+            args.extend(rustc_args.iter().map(String::as_str));
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo rustc")
+    }
+}
@@ -0,0 +1,166 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_doc::generated_index;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Build documentation for a single target with extra flags passed to rustdoc
+///
+/// Unlike cargo_doc, this supports flags like `--cfg docsrs` or
+/// `-Z unstable-options --show-coverage` (the latter needs a nightly toolchain).
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_rustdoc")]
+pub struct CargoRustdoc {
+    /// Flags passed to rustdoc after `--` (e.g., ['--cfg', 'docsrs'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rustdoc_args: Option<Vec<String>>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Document the library target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Optional binary target to document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Include private items in the documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub document_private_items: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoRustdoc {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Build docs the way docs.rs does, with all features",
+                item: Self {
+                    lib: Some(true),
+                    all_features: Some(true),
+                    rustdoc_args: Some(vec!["--cfg".into(), "docsrs".into()]),
+                    toolchain: Some("nightly".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show how much of the public API is documented",
+                item: Self {
+                    lib: Some(true),
+                    toolchain: Some("nightly".into()),
+                    rustdoc_args: Some(vec![
+                        "-Z".into(),
+                        "unstable-options".into(),
+                        "--show-coverage".into(),
+                    ]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoRustdoc {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["rustdoc"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.document_private_items.unwrap_or(false) || self.rustdoc_args.is_some() {
+            args.push("--");
+        }
+
+        if self.document_private_items.unwrap_or(false) {
+            args.push("--document-private-items");
+        }
+
+        if let Some(ref rustdoc_args) = self.rustdoc_args {
+            args.extend(rustdoc_args.iter().map(String::as_str));
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo rustdoc");
+
+        if let Some(index) = generated_index(&output.stderr) {
+            result.push_str(&format!("📚 Documentation index: {}\n", index.display()));
+        }
+
+        Ok(result)
+    }
+}
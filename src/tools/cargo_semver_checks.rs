@@ -0,0 +1,185 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Check whether the current changes require a major, minor or patch version bump with
+/// cargo semver-checks (requires cargo-semver-checks)
+///
+/// Compares against the latest registry version unless another baseline is given.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_semver_checks")]
+pub struct CargoSemverChecks {
+    /// Optional package name to check (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Check all library packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional registry version to use as the baseline (e.g., '1.2.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub baseline_version: Option<String>,
+
+    /// Optional git revision to use as the baseline (e.g., 'main' or a commit hash)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub baseline_rev: Option<String>,
+
+    /// Optional local directory containing the baseline version of the crate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub baseline_root: Option<String>,
+
+    /// Optional release type to check against instead of the version in Cargo.toml
+    /// ('major', 'minor' or 'patch')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release_type: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoSemverChecks {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Compare against the latest published version",
+                item: Self::default(),
+            },
+            Example {
+                description: "Compare against a specific published version",
+                item: Self {
+                    baseline_version: Some("1.2.0".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Compare against the main branch",
+                item: Self {
+                    baseline_rev: Some("main".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check whether the changes are fine for a minor release",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    release_type: Some("minor".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoSemverChecks {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let baselines = [
+            self.baseline_version.is_some(),
+            self.baseline_rev.is_some(),
+            self.baseline_root.is_some(),
+        ];
+        if baselines.into_iter().filter(|b| *b).count() > 1 {
+            return Err(anyhow!(
+                "Specify at most one of baseline_version, baseline_rev and baseline_root"
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("semver-checks", "cargo-semver-checks")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let mut args = vec!["semver-checks", "check-release", "--color", "never"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if let Some(ref version) = self.baseline_version {
+            args.extend_from_slice(&["--baseline-version", version]);
+        }
+
+        if let Some(ref rev) = self.baseline_rev {
+            args.extend_from_slice(&["--baseline-rev", rev]);
+        }
+
+        if let Some(ref root) = self.baseline_root {
+            args.extend_from_slice(&["--baseline-root", root]);
+        }
+
+        if let Some(ref release_type) = self.release_type {
+            args.extend_from_slice(&["--release-type", release_type]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo semver-checks");
+
+        if let Some(bump) = required_bump(&output.stderr) {
+            result.push_str(&format!("🔖 Required version bump: {bump}\n"));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Determine the required bump from the summary lines, e.g.
+/// "Summary semver requires new major version: 1 major and 0 minor checks failed"
+/// or "Summary no semver update required"
+fn required_bump(stderr: &str) -> Option<&'static str> {
+    let summaries: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Summary "))
+        .collect();
+
+    if summaries.is_empty() {
+        None
+    } else if summaries.iter().any(|s| s.contains("new major version")) {
+        Some("major")
+    } else if summaries.iter().any(|s| s.contains("new minor version")) {
+        Some("minor")
+    } else {
+        Some("patch (no semver-relevant changes)")
+    }
+}
@@ -0,0 +1,184 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, read_manifests,
+    run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+/// Which part of the version to bump
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+    Release,
+    Rc,
+    Beta,
+    Alpha,
+}
+
+/// Bump the version of one or all workspace members with cargo set-version (requires
+/// cargo-edit)
+///
+/// Version requirements on the bumped packages in other workspace members are updated to
+/// match. Reports every manifest that was edited.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_set_version")]
+pub struct CargoSetVersion {
+    /// Bump the version: 'major', 'minor', 'patch', 'release' (drop the pre-release
+    /// suffix), or 'rc', 'beta', 'alpha' (pre-release)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bump: Option<VersionBump>,
+
+    /// Set an explicit version instead of bumping (e.g., '2.0.0-rc.1')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub version: Option<String>,
+
+    /// Optional packages to change (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<Vec<String>>,
+
+    /// Change all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional packages to leave unchanged (with workspace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude: Option<Vec<String>>,
+
+    /// Show what would change without writing any manifests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub dry_run: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoSetVersion {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Preview a minor version bump of every workspace member",
+                item: Self {
+                    bump: Some(VersionBump::Minor),
+                    workspace: Some(true),
+                    dry_run: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Bump the patch version of one package",
+                item: Self {
+                    bump: Some(VersionBump::Patch),
+                    package: Some(vec!["my-lib".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Set an explicit pre-release version",
+                item: Self {
+                    version: Some("2.0.0-rc.1".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoSetVersion {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        if self.bump.is_some() == self.version.is_some() {
+            return Err(anyhow!("Specify exactly one of bump and version"));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("set-version", "cargo-edit")?;
+
+        let mut args = vec!["set-version"];
+
+        if let Some(bump) = self.bump {
+            args.extend_from_slice(&[
+                "--bump",
+                match bump {
+                    VersionBump::Major => "major",
+                    VersionBump::Minor => "minor",
+                    VersionBump::Patch => "patch",
+                    VersionBump::Release => "release",
+                    VersionBump::Rc => "rc",
+                    VersionBump::Beta => "beta",
+                    VersionBump::Alpha => "alpha",
+                },
+            ]);
+        }
+
+        if let Some(ref version) = self.version {
+            args.push(version);
+        }
+
+        for package in self.package.iter().flatten() {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        for package in self.exclude.iter().flatten() {
+            args.extend_from_slice(&["--exclude", package]);
+        }
+
+        let dry_run = self.dry_run.unwrap_or(false);
+        if dry_run {
+            args.push("--dry-run");
+        }
+
+        let before = read_manifests(&project_path);
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo set-version");
+
+        if output.status.success() && !dry_run {
+            let edited: Vec<&PathBuf> = before
+                .iter()
+                .filter(|(path, contents)| {
+                    std::fs::read_to_string(path).ok().as_ref() != Some(*contents)
+                })
+                .map(|(path, _)| path)
+                .collect();
+
+            if edited.is_empty() {
+                result.push_str("📝 No manifests were changed\n");
+            } else {
+                result.push_str(&format!("📝 Edited manifests ({}):\n", edited.len()));
+                for path in edited {
+                    let display = path.strip_prefix(&project_path).unwrap_or(path);
+                    result.push_str(&format!("  {}\n", display.display()));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, read_manifests,
+    run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path, process::Command};
+
+/// Sort the dependency tables in Cargo.toml alphabetically with cargo sort (requires
+/// cargo-sort)
+///
+/// Returns a diff of every manifest that changed. With check: true, only reports whether
+/// the manifests are sorted.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_sort")]
+pub struct CargoSort {
+    /// Only check whether the manifests are sorted, without changing them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub check: Option<bool>,
+
+    /// Sort the manifests of all workspace members too
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Keep blank-line separated groups of dependencies, sorting within each group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub grouped: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoSort {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Check whether every manifest in the workspace is sorted",
+                item: Self {
+                    check: Some(true),
+                    workspace: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Sort the workspace manifests, keeping dependency groups",
+                item: Self {
+                    workspace: Some(true),
+                    grouped: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoSort {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("sort", "cargo-sort")?;
+
+        let check = self.check.unwrap_or(false);
+        let mut args = vec!["sort"];
+
+        if check {
+            args.push("--check");
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if self.grouped.unwrap_or(false) {
+            args.push("--grouped");
+        }
+
+        let before = read_manifests(&project_path);
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo sort");
+
+        if !check {
+            let mut diffs = String::new();
+            for (path, old) in &before {
+                let new = std::fs::read_to_string(path).unwrap_or_default();
+                if &new != old {
+                    let label = path.strip_prefix(&project_path).unwrap_or(path);
+                    diffs.push_str(&unified_diff(old, path, &label.display().to_string())?);
+                }
+            }
+
+            if diffs.is_empty() {
+                result.push_str("✅ All manifests were already sorted\n");
+            } else {
+                result.push_str("📝 Changes:\n");
+                result.push_str(&diffs);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Diff the old contents of a file against the file on disk, using the system's diff
+fn unified_diff(old: &str, path: &Path, label: &str) -> Result<String> {
+    let old_path = std::env::temp_dir().join(format!("cargo-mcp-sort-{}.toml", std::process::id()));
+    std::fs::write(&old_path, old)?;
+
+    let output = Command::new("diff")
+        .arg("-u")
+        .args(["--label", &format!("a/{label}")])
+        .args(["--label", &format!("b/{label}")])
+        .arg(&old_path)
+        .arg(path)
+        .output();
+    let _ = std::fs::remove_file(&old_path);
+
+    Ok(match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).into_owned(),
+        Err(_) => format!("{label} was changed (install diff to see the changes)\n"),
+    })
+}
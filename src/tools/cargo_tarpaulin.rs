@@ -0,0 +1,215 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Report file format for cargo tarpaulin
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum TarpaulinFormat {
+    Xml,
+    Json,
+    Html,
+    Lcov,
+}
+
+/// Measure test coverage with cargo tarpaulin (requires cargo-tarpaulin)
+///
+/// An alternative to cargo_coverage for projects that standardize on tarpaulin.
+/// Always reports the overall coverage; report files are written only when requested.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_tarpaulin")]
+pub struct CargoTarpaulin {
+    /// Optional report formats to write: 'xml' (Cobertura), 'json', 'html' or 'lcov'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub output_format: Option<Vec<TarpaulinFormat>>,
+
+    /// Optional directory for report files (defaults to the project directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub output_dir: Option<String>,
+
+    /// Optional glob patterns of files to exclude from coverage (e.g., 'src/generated/*')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude_files: Option<Vec<String>>,
+
+    /// Optional package name to measure (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Measure all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional workspace packages to leave out (with workspace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude: Option<Vec<String>>,
+
+    /// Timeout for each test in seconds (tarpaulin defaults to 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub timeout_secs: Option<u64>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoTarpaulin {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show overall test coverage",
+                item: Self::default(),
+            },
+            Example {
+                description: "Write Cobertura XML and HTML reports, excluding generated code",
+                item: Self {
+                    output_format: Some(vec![TarpaulinFormat::Xml, TarpaulinFormat::Html]),
+                    exclude_files: Some(vec!["src/generated/*".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Measure the workspace with a longer test timeout",
+                item: Self {
+                    workspace: Some(true),
+                    timeout_secs: Some(300),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoTarpaulin {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("tarpaulin", "cargo-tarpaulin")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["tarpaulin", "--color", "never"];
+
+        let formats = self.output_format.as_deref().unwrap_or_default();
+        for format in formats {
+            args.extend_from_slice(&[
+                "--out",
+                match format {
+                    TarpaulinFormat::Xml => "Xml",
+                    TarpaulinFormat::Json => "Json",
+                    TarpaulinFormat::Html => "Html",
+                    TarpaulinFormat::Lcov => "Lcov",
+                },
+            ]);
+        }
+
+        if let Some(ref output_dir) = self.output_dir {
+            args.extend_from_slice(&["--output-dir", output_dir]);
+        }
+
+        for pattern in self.exclude_files.iter().flatten() {
+            args.extend_from_slice(&["--exclude-files", pattern]);
+        }
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--packages", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        for package in self.exclude.iter().flatten() {
+            args.extend_from_slice(&["--exclude", package]);
+        }
+
+        let timeout_str;
+        if let Some(secs) = self.timeout_secs {
+            timeout_str = secs.to_string();
+            args.extend_from_slice(&["--timeout", &timeout_str]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo tarpaulin");
+
+        // The summary is the last line, e.g. "78.26% coverage, 36/46 lines covered"
+        if let Some(summary) = output
+            .stdout
+            .lines()
+            .chain(output.stderr.lines())
+            .rfind(|line| line.contains("% coverage,"))
+        {
+            let summary = summary.rsplit("] ").next().unwrap_or(summary).trim();
+            result.push_str(&format!("📊 Coverage: {summary}\n"));
+        }
+
+        if output.status.success() && !formats.is_empty() {
+            let report_dir = match self.output_dir {
+                Some(ref dir) => project_path.join(dir),
+                None => project_path.clone(),
+            };
+            result.push_str("📄 Reports:\n");
+            for format in formats {
+                let file = match format {
+                    TarpaulinFormat::Xml => "cobertura.xml",
+                    TarpaulinFormat::Json => "tarpaulin-report.json",
+                    TarpaulinFormat::Html => "tarpaulin-report.html",
+                    TarpaulinFormat::Lcov => "lcov.info",
+                };
+                result.push_str(&format!("  {}\n", report_dir.join(file).display()));
+            }
+        }
+
+        Ok(result)
+    }
+}
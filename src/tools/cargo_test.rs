@@ -1,14 +1,23 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
-use anyhow::Result;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, format_cargo_output, run_cargo_command, run_cargo_command_with_timeout,
+};
+use crate::tools::diagnostics::{self, DiagnosticOptions};
+use crate::tools::test_results::{RepeatTally, TestReport};
+use anyhow::{Result, anyhow};
 use mcplease::{
     traits::{Tool, WithExamples},
     types::Example,
 };
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, time::Duration};
 
 /// Run cargo test to execute tests
+///
+/// Along with the output, returns a JSON summary of passed, failed and ignored tests per test
+/// binary, with the captured output of failed tests, and the compiler's diagnostics.
 #[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_test")]
 pub struct CargoTest {
@@ -17,10 +26,124 @@ pub struct CargoTest {
     #[arg(long)]
     pub package: Option<String>,
 
-    /// Optional specific test name to run
+    /// Test all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Optional packages to skip when testing the workspace (implies workspace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exclude: Option<Vec<String>>,
+
+    /// Only test this package's library (unit tests in src/lib.rs and its modules)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Only test this package's binaries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bins: Option<bool>,
+
+    /// Optional integration test to run (e.g., 'integration' for tests/integration.rs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test: Option<String>,
+
+    /// Optional example to test
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub example: Option<String>,
+
+    /// Test all targets, including examples and benchmarks (doc tests are not included)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_targets: Option<bool>,
+
+    /// Only run documentation examples (doc tests) of the library, not unit or integration
+    /// tests. Combine with package or workspace to choose the crates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub doc: Option<bool>,
+
+    /// Optional test name filters; tests whose name contains any of them are run
+    /// (a single string is accepted too)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "one_or_many"
+    )]
+    #[arg(long)]
+    pub test_name: Option<Vec<String>>,
+
+    /// Only run tests whose name matches a test_name filter exactly
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub exact: Option<bool>,
+
+    /// Only run the tests that failed in the last cargo_test run of this project (matched
+    /// exactly; can't be combined with test_name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub rerun_failed: Option<bool>,
+
+    /// Optional filters for tests to skip (substring match, or exact match with exact)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub skip: Option<Vec<String>>,
+
+    /// Build and run tests in release mode, with optimizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Optional custom profile to build with (e.g., 'test-opt'); can't be combined with release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Optional target triple to test for (e.g., 'x86_64-unknown-linux-musl', 'wasm32-wasip1').
+    /// Tests for targets the host can't run need a runner configured for the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Run only tests marked #[ignore] (e.g., slow or network-dependent tests)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub ignored: Option<bool>,
+
+    /// Run tests marked #[ignore] along with all other tests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub include_ignored: Option<bool>,
+
+    /// Only compile the tests without running them, and list the test binaries that were built
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_run: Option<bool>,
+
+    /// Optional number of threads to run tests on; use 1 for tests that share global state
+    /// (e.g., environment variables or a database) and can't run in parallel
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
-    pub test_name: Option<String>,
+    pub test_threads: Option<u32>,
 
     /// Don't capture stdout/stderr of tests, allow printing to console
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +156,39 @@ pub struct CargoTest {
     #[arg(long)]
     pub quiet: Option<bool>,
 
+    /// Optional extra arguments for the test harness (e.g., ['--show-output'] for libtest).
+    /// They are passed after `--`, following the filters and harness options set above.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long, allow_hyphen_values = true)]
+    pub harness_args: Option<Vec<String>>,
+
+    /// Run the tests this many times and report tests that failed only in some runs (flaky)
+    /// or in all of them, with pass/fail counts. The raw output is left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub repeat: Option<u32>,
+
+    /// Optional time limit in seconds, including the build (for each run with repeat). The
+    /// command and any processes it started are stopped when it runs out, and the output up
+    /// to that point is returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub timeout_secs: Option<u64>,
+
+    /// Optional number of parallel build jobs (defaults to the number of CPUs); lower it on
+    /// small or shared machines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub jobs: Option<u32>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub diagnostic_options: DiagnosticOptions,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -58,10 +214,105 @@ impl WithExamples for CargoTest {
                     ..Self::default()
                 },
             },
+            Example {
+                description: "Test the whole workspace except a slow package",
+                item: Self {
+                    workspace: Some(true),
+                    exclude: Some(vec!["integration-suite".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run only tests/integration.rs",
+                item: Self {
+                    test: Some("integration".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run only the unit tests of the library",
+                item: Self {
+                    lib: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run only the doc tests of one package",
+                item: Self {
+                    package: Some("my-lib".into()),
+                    doc: Some(true),
+                    ..Self::default()
+                },
+            },
             Example {
                 description: "Run a specific test",
                 item: Self {
-                    test_name: Some("test_addition".into()),
+                    test_name: Some(vec!["test_addition".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run one test by its exact full name",
+                item: Self {
+                    test_name: Some(vec!["parser::tests::parses_empty_input".into()]),
+                    exact: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Rerun only the tests that failed last time",
+                item: Self {
+                    rerun_failed: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests matching several filters, skipping a slow one",
+                item: Self {
+                    test_name: Some(vec!["parser".into(), "lexer".into()]),
+                    skip: Some(vec!["parser::tests::huge_input".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests with all features enabled",
+                item: Self {
+                    all_features: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run slow integration tests in release mode",
+                item: Self {
+                    release: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests for a musl target",
+                item: Self {
+                    target: Some("x86_64-unknown-linux-musl".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run the ignored tests",
+                item: Self {
+                    ignored: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Check that the tests compile and list the test binaries",
+                item: Self {
+                    no_run: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests one at a time",
+                item: Self {
+                    test_threads: Some(1),
                     ..Self::default()
                 },
             },
@@ -72,6 +323,35 @@ impl WithExamples for CargoTest {
                     ..Self::default()
                 },
             },
+            Example {
+                description: "Show the output of passing tests too",
+                item: Self {
+                    harness_args: Some(vec!["--show-output".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Look for flaky tests by running a test binary 20 times",
+                item: Self {
+                    test: Some("integration".into()),
+                    repeat: Some(20),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests, giving up after five minutes",
+                item: Self {
+                    timeout_secs: Some(300),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Run tests with at most two parallel build jobs",
+                item: Self {
+                    jobs: Some(2),
+                    ..Self::default()
+                },
+            },
             Example {
                 description: "Run tests with custom environment",
                 item: Self {
@@ -98,13 +378,59 @@ impl WithExamples for CargoTest {
 
 impl Tool<CargoTools> for CargoTest {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
+        if self.release.unwrap_or(false) && self.profile.is_some() {
+            return Err(anyhow!(
+                "release and profile can't be combined; use profile: 'release' instead"
+            ));
+        }
+
+        if self.ignored.unwrap_or(false) && self.include_ignored.unwrap_or(false) {
+            return Err(anyhow!(
+                "ignored and include_ignored can't be combined; ignored runs only ignored \
+                 tests, include_ignored runs them along with the rest"
+            ));
+        }
+
+        let project_path = self.cargo_options.project_path(state)?;
+
+        let mut test_names = self.test_name.unwrap_or_default();
+        let mut exact = self.exact.unwrap_or(false);
+        if self.rerun_failed.unwrap_or(false) {
+            if !test_names.is_empty() {
+                return Err(anyhow!("rerun_failed and test_name can't be combined"));
+            }
+            test_names = state.get_failed_tests(&project_path, None)?;
+            if test_names.is_empty() {
+                return Ok(
+                    "ℹ️  No failed tests are recorded for this project. Run cargo_test first.\n"
+                        .into(),
+                );
+            }
+            exact = true;
+        }
 
         // Use toolchain from args, session default, or none
         let toolchain = self
             .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Use profile from args, session default, or none. Asking for release or not
+        // overrides the default too.
+        let profile = match self.release {
+            Some(_) => self.profile,
+            None => self
+                .profile
+                .or_else(|| state.get_default_profile(None).unwrap_or(None)),
+        };
+
         let mut args = vec!["test"];
 
         if self.quiet.unwrap_or(false) {
@@ -115,16 +441,270 @@ impl Tool<CargoTools> for CargoTest {
             args.extend_from_slice(&["--package", package]);
         }
 
-        if let Some(ref test_name) = self.test_name {
-            args.push(test_name);
+        // cargo only accepts --exclude together with --workspace
+        let exclude = self.exclude.unwrap_or_default();
+        if self.workspace.unwrap_or(false) || !exclude.is_empty() {
+            args.push("--workspace");
+        }
+
+        for package in &exclude {
+            args.extend_from_slice(&["--exclude", package]);
+        }
+
+        if self.release.unwrap_or(false) {
+            args.push("--release");
+        }
+
+        if let Some(ref profile) = profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if self.bins.unwrap_or(false) {
+            args.push("--bins");
+        }
+
+        if let Some(ref test) = self.test {
+            args.extend_from_slice(&["--test", test]);
+        }
+
+        if let Some(ref example) = self.example {
+            args.extend_from_slice(&["--example", example]);
+        }
+
+        if self.all_targets.unwrap_or(false) {
+            args.push("--all-targets");
+        }
+
+        let jobs_str;
+        if let Some(jobs) = self.jobs {
+            jobs_str = jobs.to_string();
+            args.extend_from_slice(&["--jobs", &jobs_str]);
+        }
+
+        if self.doc.unwrap_or(false) {
+            args.push("--doc");
+        }
+
+        // Every run has to run every test binary for the counts to add up
+        let repeat = self.repeat.unwrap_or(1).max(1);
+        if repeat > 1 {
+            args.push("--no-fail-fast");
+        }
+
+        // Compiler diagnostics are parsed from cargo's JSON messages, which also tell
+        // where the test binaries are
+        args.extend_from_slice(&["--message-format", diagnostics::MESSAGE_FORMAT]);
+        let no_run = self.no_run.unwrap_or(false);
+        if no_run {
+            args.push("--no-run");
+        }
+
+        // Options for the test harness go after --
+        // cargo only takes one test name, so all filters are passed to the harness
+        let mut harness_args: Vec<&str> = test_names.iter().map(String::as_str).collect();
+
+        if exact {
+            harness_args.push("--exact");
+        }
+
+        for skip in self.skip.iter().flatten() {
+            harness_args.extend_from_slice(&["--skip", skip]);
+        }
+
+        if self.ignored.unwrap_or(false) {
+            harness_args.push("--ignored");
+        }
+
+        if self.include_ignored.unwrap_or(false) {
+            harness_args.push("--include-ignored");
+        }
+
+        let test_threads_arg;
+        if let Some(test_threads) = self.test_threads {
+            test_threads_arg = format!("--test-threads={test_threads}");
+            harness_args.push(&test_threads_arg);
         }
 
         // Add --nocapture if requested
         if self.no_capture.unwrap_or(false) {
-            args.extend_from_slice(&["--", "--nocapture"]);
+            harness_args.push("--nocapture");
+        }
+
+        // The separator is added below, so drop one the caller may have included
+        if let Some(ref extra_args) = self.harness_args {
+            let extra_args = match extra_args.split_first() {
+                Some((first, rest)) if first == "--" => rest,
+                _ => extra_args,
+            };
+            harness_args.extend(extra_args.iter().map(String::as_str));
+        }
+
+        // Nothing is run with no_run, so there's no harness to pass options to
+        if !harness_args.is_empty() && !no_run {
+            args.push("--");
+            args.extend(harness_args);
+        }
+
+        let timeout = self.timeout_secs.map(Duration::from_secs);
+        let keep_color = cargo_options.global.keeps_color();
+        let run = || {
+            let cmd = create_cargo_command(
+                &args,
+                toolchain.as_deref(),
+                self.cargo_env.as_ref(),
+                &cargo_options,
+            );
+            match timeout {
+                Some(timeout) => run_cargo_command_with_timeout(cmd, &project_path, timeout),
+                None => run_cargo_command(cmd, &project_path),
+            }
+        };
+
+        if no_run {
+            let mut output = run()?;
+            let binaries = summarize_test_binaries(&output.stdout);
+            let diagnostics = self.diagnostic_options.take(
+                state,
+                &mut output,
+                &project_path,
+                "cargo test",
+                keep_color,
+            )?;
+            output.stdout = binaries;
+            let mut result = format_cargo_output(&output, &project_path, "cargo test");
+            result.push_str(&self.diagnostic_options.format(&diagnostics));
+            return Ok(result);
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo test")
+        if repeat == 1 {
+            let mut output = run()?;
+            let diagnostics = self.diagnostic_options.take(
+                state,
+                &mut output,
+                &project_path,
+                "cargo test",
+                keep_color,
+            )?;
+            let mut result = format_cargo_output(&output, &project_path, "cargo test");
+            result.push_str(&self.diagnostic_options.format(&diagnostics));
+            let report = TestReport::parse(&output.stdout, &output.stderr);
+            if !report.suites.is_empty() {
+                // A run that timed out doesn't tell which of the remaining tests fail
+                if output.timed_out.is_none() {
+                    state.set_failed_tests(project_path.clone(), report.failed_tests(), None)?;
+                }
+                result.push_str(&format!(
+                    "📊 Test results:\n{}\n",
+                    serde_json::to_string_pretty(&report)?
+                ));
+            }
+            return Ok(result);
+        }
+
+        let mut tally = RepeatTally::default();
+        let mut command = String::new();
+        for run_number in 1..=repeat {
+            let mut output = run()?;
+            let diagnostics = self.diagnostic_options.take(
+                state,
+                &mut output,
+                &project_path,
+                "cargo test",
+                keep_color,
+            )?;
+            let report = TestReport::parse(&output.stdout, &output.stderr);
+            if report.suites.is_empty() || output.timed_out.is_some() {
+                // Most likely the tests didn't build, which running again won't change
+                let mut result = format!("⚠️  Run {run_number} of {repeat} didn't complete\n\n");
+                result.push_str(&format_cargo_output(&output, &project_path, "cargo test"));
+                result.push_str(&self.diagnostic_options.format(&diagnostics));
+                return Ok(result);
+            }
+            tally.add(&report);
+            command = output.command;
+        }
+        state.set_failed_tests(project_path.clone(), tally.failed_tests(), None)?;
+
+        let summary = tally.summary();
+        let mut result = format!("=== cargo test (repeated {repeat} times) ===\n");
+        result.push_str(&format!(
+            "📁 Working directory: {}\n",
+            project_path.display()
+        ));
+        result.push_str(&format!("🔧 Command: {command}\n\n"));
+        if summary.flaky.is_empty() && summary.always_failed.is_empty() {
+            result.push_str(&format!("✅ No test failed in {repeat} runs\n"));
+        } else {
+            result.push_str(&format!(
+                "🎲 Flaky tests: {}, always failing: {}\n\n",
+                summary.flaky.len(),
+                summary.always_failed.len()
+            ));
+            result.push_str(&serde_json::to_string_pretty(&summary)?);
+            result.push('\n');
+        }
+
+        Ok(result)
+    }
+}
+
+/// List the test executables from cargo's JSON messages
+fn summarize_test_binaries(stdout: &str) -> String {
+    let binaries: Vec<String> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|message| {
+            message["reason"] == "compiler-artifact" && message["profile"]["test"] == true
+        })
+        .filter_map(|message| {
+            let executable = message["executable"].as_str()?;
+            let name = message["target"]["name"].as_str().unwrap_or("?");
+            let kind = message["target"]["kind"][0].as_str().unwrap_or("?");
+            Some(format!("  {name} ({kind}): {executable}\n"))
+        })
+        .collect();
+
+    if binaries.is_empty() {
+        "No test binaries were built\n".into()
+    } else {
+        format!("🧪 Test binaries:\n{}", binaries.concat())
     }
 }
+
+/// Accept a single filter as well as a list, since test_name used to be a plain string
+fn one_or_many<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<String>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::One(filter)) => Some(vec![filter]),
+        Some(OneOrMany::Many(filters)) => Some(filters),
+        None => None,
+    })
+}
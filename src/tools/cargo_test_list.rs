@@ -0,0 +1,234 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use crate::tools::test_results::test_binaries;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// List the tests cargo test would run, grouped by test binary and module, without running
+/// them. Use the names as exact test_name filters for cargo_test.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_test_list")]
+pub struct CargoTestList {
+    /// Optional package name to list tests of (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// List tests of all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Only list tests of this package's library
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub lib: Option<bool>,
+
+    /// Only list tests of this package's binaries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bins: Option<bool>,
+
+    /// Optional integration test to list the tests of (e.g., 'integration' for
+    /// tests/integration.rs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test: Option<String>,
+
+    /// Only list doc tests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub doc: Option<bool>,
+
+    /// Optional filters; only tests whose name contains one of them are listed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub test_name: Option<Vec<String>>,
+
+    /// Only list tests marked #[ignore]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub ignored: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoTestList {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List all tests in the current project",
+                item: Self::default(),
+            },
+            Example {
+                description: "List the tests of tests/integration.rs",
+                item: Self {
+                    test: Some("integration".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "List the ignored tests in the workspace",
+                item: Self {
+                    workspace: Some(true),
+                    ignored: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoTestList {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["test"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if self.lib.unwrap_or(false) {
+            args.push("--lib");
+        }
+
+        if self.bins.unwrap_or(false) {
+            args.push("--bins");
+        }
+
+        if let Some(ref test) = self.test {
+            args.extend_from_slice(&["--test", test]);
+        }
+
+        if self.doc.unwrap_or(false) {
+            args.push("--doc");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        args.extend_from_slice(&["--", "--list"]);
+
+        if self.ignored.unwrap_or(false) {
+            args.push("--ignored");
+        }
+
+        args.extend(self.test_name.iter().flatten().map(String::as_str));
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+        if output.status.success() {
+            output.stdout = summarize_test_list(&output.stdout, &output.stderr);
+            // The listing says everything the build and run messages would
+            output.stderr.clear();
+        }
+
+        Ok(format_cargo_output(
+            &output,
+            &project_path,
+            "cargo test --list",
+        ))
+    }
+}
+
+/// Group the harness' "name: test" lines by test binary and module. Each binary's list ends
+/// with a "3 tests, 0 benchmarks" count.
+fn summarize_test_list(stdout: &str, stderr: &str) -> String {
+    let mut binaries = test_binaries(stderr);
+    let mut result = String::new();
+    let mut tests = Vec::new();
+
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_suffix(": test")
+            .or_else(|| line.strip_suffix(": benchmark"))
+        {
+            tests.push(name);
+        } else if line.ends_with(" benchmarks") || line.ends_with(" benchmark") {
+            let binary = binaries.next().unwrap_or_default();
+            result.push_str(&format!("📦 {binary}: {}\n", line.trim()));
+            // Doc test names are "src/lib.rs - path::to::item (line 3)" and stay as they are
+            if binary.starts_with("Doc-tests") {
+                for test in tests.drain(..) {
+                    result.push_str(&format!("  {test}\n"));
+                }
+                continue;
+            }
+
+            let mut modules: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+            for test in tests.drain(..) {
+                let (module, name) = test.rsplit_once("::").unwrap_or(("", test));
+                modules.entry(module).or_default().push(name);
+            }
+            for (module, names) in modules {
+                let indent = if module.is_empty() {
+                    "  "
+                } else {
+                    result.push_str(&format!("  {module}::\n"));
+                    "    "
+                };
+                for name in names {
+                    result.push_str(&format!("{indent}{name}\n"));
+                }
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,183 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Display the dependency tree with cargo tree
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_tree")]
+pub struct CargoTree {
+    /// Optional package name to display the tree for (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Invert the tree for this package, showing what depends on it.
+    /// Use this to answer "what pulls in crate X".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub invert: Option<String>,
+
+    /// Comma-separated dependency kinds to display
+    /// (e.g., 'normal', 'dev', 'build', 'features', 'no-dev', 'all')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub edges: Option<String>,
+
+    /// Maximum display depth of the tree
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub depth: Option<u32>,
+
+    /// Show only dependencies which come in multiple versions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub duplicates: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    /// Do not activate the `default` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_default_features: Option<bool>,
+
+    /// Filter dependencies matching the given target triple, or 'all' for all targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub target: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoTree {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show the full dependency tree",
+                item: Self::default(),
+            },
+            Example {
+                description: "Find out what pulls in a crate",
+                item: Self {
+                    invert: Some("syn".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show only direct dependencies",
+                item: Self {
+                    depth: Some(1),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "List crates that appear in multiple versions",
+                item: Self {
+                    duplicates: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show normal dependencies with feature edges for a target",
+                item: Self {
+                    edges: Some("normal,features".into()),
+                    target: Some("x86_64-pc-windows-msvc".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoTree {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["tree"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref invert) = self.invert {
+            args.extend_from_slice(&["--invert", invert]);
+        }
+
+        if let Some(ref edges) = self.edges {
+            args.extend_from_slice(&["--edges", edges]);
+        }
+
+        let depth_str;
+        if let Some(depth) = self.depth {
+            depth_str = depth.to_string();
+            args.extend_from_slice(&["--depth", &depth_str]);
+        }
+
+        if self.duplicates.unwrap_or(false) {
+            args.push("--duplicates");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        if self.no_default_features.unwrap_or(false) {
+            args.push("--no-default-features");
+        }
+
+        if let Some(ref target) = self.target {
+            args.extend_from_slice(&["--target", target]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        execute_cargo_command(cmd, &project_path, "cargo tree")
+    }
+}
@@ -0,0 +1,167 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Find unused dependencies with cargo udeps (requires cargo-udeps)
+///
+/// Uses the nightly toolchain unless another toolchain is given.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_udeps")]
+pub struct CargoUdeps {
+    /// Optional package name to check (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Check all packages in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub workspace: Option<bool>,
+
+    /// Check all targets, so dev-dependencies used only by tests and benches count as used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_targets: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Activate all available features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_features: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional nightly toolchain to use (defaults to 'nightly')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoUdeps {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Find unused dependencies in the current package",
+                item: Self::default(),
+            },
+            Example {
+                description: "Find unused dependencies across the workspace, including dev-dependencies",
+                item: Self {
+                    workspace: Some(true),
+                    all_targets: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoUdeps {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("udeps", "cargo-udeps")?;
+
+        // cargo udeps relies on unstable compiler output, so it always needs nightly
+        let toolchain = self.toolchain.unwrap_or_else(|| "nightly".into());
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let mut args = vec!["udeps", "--output", "json"];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if self.workspace.unwrap_or(false) {
+            args.push("--workspace");
+        }
+
+        if self.all_targets.unwrap_or(false) {
+            args.push("--all-targets");
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        if self.all_features.unwrap_or(false) {
+            args.push("--all-features");
+        }
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(
+            &args,
+            Some(&toolchain),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let mut output = run_cargo_command(cmd, &project_path)?;
+
+        // cargo udeps exits non-zero when it finds unused dependencies
+        if let Ok(report) = serde_json::from_str::<Value>(&output.stdout) {
+            output.stdout = summarize_report(&report);
+        }
+
+        Ok(format_cargo_output(&output, &project_path, "cargo udeps"))
+    }
+}
+
+/// List unused dependencies per workspace member, grouped by dependency kind
+fn summarize_report(report: &Value) -> String {
+    let Some(members) = report["unused_deps"].as_object().filter(|m| !m.is_empty()) else {
+        return "✅ No unused dependencies found\n".into();
+    };
+
+    let mut summary = String::from("🧹 Unused dependencies:\n");
+    for (member, unused) in members {
+        // Members are keyed by package id, e.g. "my-lib 0.1.0 (path+file:///...)"
+        let name = member.split(" (").next().unwrap_or(member);
+        summary.push_str(&format!("  {name}:\n"));
+        for (kind, label) in [
+            ("normal", "dependencies"),
+            ("development", "dev-dependencies"),
+            ("build", "build-dependencies"),
+        ] {
+            let deps: Vec<&str> = unused[kind]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect();
+            if !deps.is_empty() {
+                summary.push_str(&format!("    {label}: {}\n", deps.join(", ")));
+            }
+        }
+    }
+
+    if let Some(note) = report["note"].as_str() {
+        summary.push_str(&format!("\nℹ️  {note}\n"));
+    }
+
+    summary
+}
@@ -1,5 +1,6 @@
 use crate::state::CargoTools;
-use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
 use anyhow::Result;
 use mcplease::{
     traits::{Tool, WithExamples},
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Update dependencies using cargo update
-#[derive(Debug, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
 #[serde(rename = "cargo_update")]
 pub struct CargoUpdate {
     /// Optional package name (for workspaces)
@@ -27,6 +28,21 @@ pub struct CargoUpdate {
     #[arg(long)]
     pub dry_run: Option<bool>,
 
+    /// Also update the dependencies of the specified dependencies
+    /// (`--recursive`, formerly `--aggressive`)
+    #[serde(skip_serializing_if = "Option::is_none", alias = "aggressive")]
+    #[arg(long, alias = "aggressive")]
+    pub recursive: Option<bool>,
+
+    /// Update a single dependency to exactly this version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub precise: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
     /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
     #[serde(skip_serializing_if = "Option::is_none")]
     #[arg(long)]
@@ -43,42 +59,43 @@ impl WithExamples for CargoUpdate {
         vec![
             Example {
                 description: "Update all dependencies",
-                item: Self {
-                    package: None,
-                    dependencies: None,
-                    dry_run: None,
-                    toolchain: None,
-                    cargo_env: None,
-                },
+                item: Self::default(),
             },
             Example {
                 description: "Dry run to see what would be updated",
                 item: Self {
-                    package: None,
-                    dependencies: None,
                     dry_run: Some(true),
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Update specific dependencies",
                 item: Self {
-                    package: None,
                     dependencies: Some(vec!["serde".into(), "tokio".into()]),
-                    dry_run: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
                 },
             },
             Example {
                 description: "Update dependencies for a specific package",
                 item: Self {
                     package: Some("my-lib".into()),
-                    dependencies: None,
-                    dry_run: None,
-                    toolchain: None,
-                    cargo_env: None,
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Update a dependency and everything it depends on",
+                item: Self {
+                    dependencies: Some(vec!["tokio".into()]),
+                    recursive: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Pin a dependency to an exact version",
+                item: Self {
+                    dependencies: Some(vec!["serde".into()]),
+                    precise: Some("1.0.200".into()),
+                    ..Self::default()
                 },
             },
         ]
@@ -87,15 +104,18 @@ impl WithExamples for CargoUpdate {
 
 impl Tool<CargoTools> for CargoUpdate {
     fn execute(self, state: &mut CargoTools) -> Result<String> {
-        let project_path = state.ensure_rust_project(None)?;
-        
+        let project_path = self.cargo_options.project_path(state)?;
+
         // Use toolchain from args, session default, or none
-        let toolchain = self.toolchain
+        let toolchain = self
+            .toolchain
             .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
 
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
 
         let mut args = vec!["update"];
-        
+
         if let Some(ref package) = self.package {
             args.extend_from_slice(&["--package", package]);
         }
@@ -104,6 +124,14 @@ impl Tool<CargoTools> for CargoUpdate {
             args.push("--dry-run");
         }
 
+        if self.recursive.unwrap_or(false) {
+            args.push("--recursive");
+        }
+
+        if let Some(ref precise) = self.precise {
+            args.extend_from_slice(&["--precise", precise]);
+        }
+
         // Add specific dependencies to update if provided
         if let Some(ref deps) = self.dependencies {
             for dep in deps {
@@ -111,7 +139,54 @@ impl Tool<CargoTools> for CargoUpdate {
             }
         }
 
-        let cmd = create_cargo_command(&args, toolchain.as_deref(), self.cargo_env.as_ref());
-        execute_cargo_command(cmd, &project_path, "cargo update")
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo update");
+        result.push_str(&lockfile_summary(&output.stderr));
+        Ok(result)
+    }
+}
+
+/// Group the lockfile changes cargo update reported by kind of change
+fn lockfile_summary(stderr: &str) -> String {
+    let mut updated = vec![];
+    let mut added = vec![];
+    let mut removed = vec![];
+
+    for line in stderr.lines() {
+        let line = line.trim();
+        if let Some(rest) = line
+            .strip_prefix("Updating ")
+            .or_else(|| line.strip_prefix("Downgrading "))
+        {
+            // Skip registry index updates, which have no version arrow
+            if rest.contains(" -> ") {
+                updated.push(rest);
+            }
+        } else if let Some(rest) = line.strip_prefix("Adding ") {
+            added.push(rest);
+        } else if let Some(rest) = line.strip_prefix("Removing ") {
+            removed.push(rest);
+        }
+    }
+
+    if updated.is_empty() && added.is_empty() && removed.is_empty() {
+        return "📋 Lockfile changes: none\n".into();
+    }
+
+    let mut summary = String::from("📋 Lockfile changes:\n");
+    for (label, entries) in [("Updated", updated), ("Added", added), ("Removed", removed)] {
+        if !entries.is_empty() {
+            summary.push_str(&format!("  {label} ({}):\n", entries.len()));
+            for entry in entries {
+                summary.push_str(&format!("    • {entry}\n"));
+            }
+        }
     }
+    summary
 }
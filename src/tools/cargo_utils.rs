@@ -1,22 +1,31 @@
-use anyhow::Result;
-use std::{collections::HashMap, path::PathBuf, process::Command};
+use crate::tools::cargo_options::CargoOptions;
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
 
-/// Helper to create a cargo command with optional toolchain and environment variables
+/// Helper to create a cargo command with optional toolchain, environment variables and
+/// global cargo options
 pub fn create_cargo_command(
     cargo_args: &[&str],
     toolchain: Option<&str>,
     env_vars: Option<&HashMap<String, String>>,
+    options: &CargoOptions,
 ) -> Command {
     let mut cmd = if let Some(toolchain) = toolchain {
         let mut cmd = Command::new("rustup");
         cmd.args(["run", toolchain, "cargo"]);
-        cmd.args(cargo_args);
         cmd
     } else {
-        let mut cmd = Command::new("cargo");
-        cmd.args(cargo_args);
-        cmd
+        Command::new("cargo")
     };
+    options.apply(&mut cmd, cargo_args);
 
     // Apply environment variables if provided
     if let Some(env_map) = env_vars {
@@ -28,26 +37,295 @@ pub fn create_cargo_command(
     cmd
 }
 
-/// Execute a cargo command and format the output for MCP response
-pub fn execute_cargo_command(
-    mut cmd: Command,
-    project_path: &PathBuf,
-    command_name: &str,
-) -> Result<String> {
+/// Whether a program (e.g., `cross`) is on the PATH
+pub fn program_installed(program: &str) -> bool {
+    let binary = format!("{program}{}", std::env::consts::EXE_SUFFIX);
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(&binary).is_file()))
+}
+
+/// Whether an external cargo subcommand (e.g., `cargo-expand`) is on the PATH
+pub fn cargo_subcommand_installed(subcommand: &str) -> bool {
+    program_installed(&format!("cargo-{subcommand}"))
+}
+
+/// Return a helpful error if an external cargo subcommand isn't installed
+pub fn ensure_cargo_subcommand(subcommand: &str, crate_name: &str) -> Result<()> {
+    if cargo_subcommand_installed(subcommand) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "cargo {subcommand} is not installed. Install it with cargo_install \
+             (crate_name: '{crate_name}') and try again."
+        ))
+    }
+}
+
+/// Return a helpful error if a standalone program installed with cargo isn't on the PATH
+pub fn ensure_program(program: &str, crate_name: &str) -> Result<()> {
+    if program_installed(program) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{program} is not installed. Install it with cargo_install \
+             (crate_name: '{crate_name}') and try again."
+        ))
+    }
+}
+
+/// Make sure a rustup component (e.g., `miri`) is installed for a toolchain, installing
+/// it if it's missing. Returns a note for the response if anything was installed.
+pub fn ensure_rustup_component(component: &str, toolchain: &str) -> Result<Option<String>> {
+    let installed = Command::new("rustup")
+        .args(["component", "list", "--installed", "--toolchain", toolchain])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&installed.stdout);
+    let is_installed = stdout
+        .lines()
+        .any(|line| line == component || line.starts_with(&format!("{component}-")));
+
+    if is_installed {
+        return Ok(None);
+    }
+
+    let output = Command::new("rustup")
+        .args(["component", "add", component, "--toolchain", toolchain])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Could not install the {component} component for the {toolchain} toolchain:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(Some(format!(
+        "🔩 Installed the {component} component for the {toolchain} toolchain\n"
+    )))
+}
+
+/// Captured result of running a cargo command
+#[derive(Debug)]
+pub struct CargoOutput {
+    /// The command line as displayed to the user
+    pub command: String,
+    /// Exit status of the process
+    pub status: ExitStatus,
+    /// Captured standard output
+    pub stdout: String,
+    /// Captured standard error
+    pub stderr: String,
+    /// Set if the command was stopped because it ran into its timeout
+    pub timed_out: Option<Duration>,
+}
+
+/// Run a cargo command in the project directory and capture its output
+pub fn run_cargo_command(mut cmd: Command, project_path: &PathBuf) -> Result<CargoOutput> {
     cmd.current_dir(project_path);
 
     let output = cmd.output()?;
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    let clean = clean_output(&cmd);
+
+    Ok(CargoOutput {
+        command: format_command(&cmd),
+        status: output.status,
+        stdout: clean(String::from_utf8_lossy(&output.stdout).into_owned()),
+        stderr: clean(String::from_utf8_lossy(&output.stderr).into_owned()),
+        timed_out: None,
+    })
+}
+
+/// Run a cargo command like `run_cargo_command`, but stop it (along with any processes it
+/// spawned) if it is still running after `timeout`. Output produced up to that point is kept.
+pub fn run_cargo_command_with_timeout(
+    cmd: Command,
+    project_path: &PathBuf,
+    timeout: Duration,
+) -> Result<CargoOutput> {
+    run_with_deadline(cmd, project_path, timeout, None)
+}
+
+/// Like `run_cargo_command_with_timeout`, but on timeout first interrupt the command as if
+/// Ctrl-C was pressed, and only kill it if it is still running after `grace`. This lets
+/// tools like cargo flamegraph finish writing their results.
+pub fn run_cargo_command_with_interrupt(
+    cmd: Command,
+    project_path: &PathBuf,
+    timeout: Duration,
+    grace: Duration,
+) -> Result<CargoOutput> {
+    run_with_deadline(cmd, project_path, timeout, Some(grace))
+}
+
+fn run_with_deadline(
+    mut cmd: Command,
+    project_path: &PathBuf,
+    timeout: Duration,
+    interrupt_grace: Option<Duration>,
+) -> Result<CargoOutput> {
+    cmd.current_dir(project_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Put the command in its own process group so rustc, test binaries etc. can be
+    // stopped together with cargo
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    let mut child = cmd.spawn()?;
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let started = Instant::now();
+    let mut timed_out = None;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let elapsed = started.elapsed();
+        match interrupt_grace {
+            Some(grace) if elapsed >= timeout + grace => {
+                kill_process_tree(&mut child);
+                break child.wait()?;
+            }
+            Some(_) if elapsed >= timeout && timed_out.is_none() => {
+                signal_process_group(&child, "-INT");
+                timed_out = Some(timeout);
+            }
+            None if elapsed >= timeout => {
+                kill_process_tree(&mut child);
+                timed_out = Some(timeout);
+                break child.wait()?;
+            }
+            _ => {}
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+
+    let clean = clean_output(&cmd);
+    Ok(CargoOutput {
+        command: format_command(&cmd),
+        status,
+        stdout: clean(stdout.join().unwrap_or_default()),
+        stderr: clean(stderr.join().unwrap_or_default()),
+        timed_out,
+    })
+}
+
+/// How to clean up the captured output of a command: escape sequences are stripped unless
+/// the command asked for them with --color always, or CARGO_TERM_COLOR without a --color
+fn clean_output(cmd: &Command) -> fn(String) -> String {
+    let args: Vec<_> = cmd.get_args().collect();
+    let color = match args.iter().position(|arg| *arg == "--color") {
+        Some(index) => args.get(index + 1).copied(),
+        None => cmd
+            .get_envs()
+            .find(|(key, _)| *key == "CARGO_TERM_COLOR")
+            .and_then(|(_, value)| value),
+    };
+    if color.is_some_and(|color| color == "always") {
+        |output| output
+    } else {
+        |output| strip_ansi(&output)
+    }
+}
+
+/// Remove terminal escape sequences, such as colors and hyperlinks, from output
+pub fn strip_ansi(text: &str) -> String {
+    if !text.contains('\u{1b}') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            // Control sequences (colors, cursor movement) end with a byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // Operating system commands (hyperlinks) end with BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+                    if c == '\u{1b}' {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // Character set designations (e.g., ESC ( B) are followed by the character set
+            Some('(' | ')' | '*' | '+') => {
+                chars.next();
+            }
+            // Anything else is a two-character sequence
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Drain a child's output pipe on a separate thread, so a full pipe can't block the child
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        String::from_utf8_lossy(&buffer).into_owned()
+    })
+}
+
+/// Stop a child process and, on unix, every process in its process group
+fn kill_process_tree(child: &mut Child) {
+    signal_process_group(child, "-KILL");
+    let _ = child.kill();
+}
+
+/// Send a signal (e.g., "-INT") to every process in a child's process group (unix only)
+fn signal_process_group(child: &Child, signal: &str) {
+    #[cfg(unix)]
+    let _ = Command::new("kill")
+        .args([signal, "--", &format!("-{}", child.id())])
+        .status();
+    #[cfg(not(unix))]
+    let _ = (child, signal);
+}
+
+/// Format captured cargo output for MCP response
+pub fn format_cargo_output(
+    output: &CargoOutput,
+    project_path: &Path,
+    command_name: &str,
+) -> String {
+    let stdout = &output.stdout;
+    let stderr = &output.stderr;
 
     let mut result = format!("=== {command_name} ===\n");
     result.push_str(&format!(
         "📁 Working directory: {}\n",
         project_path.display()
     ));
-    result.push_str(&format!("🔧 Command: {}\n\n", format_command(&cmd)));
+    result.push_str(&format!("🔧 Command: {}\n\n", output.command));
 
-    if output.status.success() {
+    if let Some(timeout) = output.timed_out {
+        result.push_str(&format!(
+            "⏱️  Command timed out after {}s and was stopped\n\n",
+            timeout.as_secs()
+        ));
+    } else if output.status.success() {
         result.push_str("✅ Command completed successfully\n\n");
     } else {
         result.push_str(&format!(
@@ -58,7 +336,7 @@ pub fn execute_cargo_command(
 
     if !stdout.is_empty() {
         result.push_str("📤 STDOUT:\n");
-        result.push_str(&stdout);
+        result.push_str(stdout);
         if !stdout.ends_with('\n') {
             result.push('\n');
         }
@@ -67,7 +345,7 @@ pub fn execute_cargo_command(
 
     if !stderr.is_empty() {
         result.push_str("📤 STDERR:\n");
-        result.push_str(&stderr);
+        result.push_str(stderr);
         if !stderr.ends_with('\n') {
             result.push('\n');
         }
@@ -78,7 +356,75 @@ pub fn execute_cargo_command(
         result.push_str("ℹ️  No output produced\n");
     }
 
-    Ok(result)
+    result
+}
+
+/// Execute a cargo command and format the output for MCP response
+pub fn execute_cargo_command(
+    cmd: Command,
+    project_path: &PathBuf,
+    command_name: &str,
+) -> Result<String> {
+    let output = run_cargo_command(cmd, project_path)?;
+    Ok(format_cargo_output(&output, project_path, command_name))
+}
+
+/// The root directory of the workspace the project belongs to, where Cargo.lock is
+pub fn workspace_root(project_path: &Path) -> Option<PathBuf> {
+    let output = create_cargo_command(
+        &["locate-project", "--workspace", "--message-format", "plain"],
+        None,
+        None,
+        &CargoOptions::default(),
+    )
+    .current_dir(project_path)
+    .output()
+    .ok()
+    .filter(|output| output.status.success())?;
+
+    let manifest = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    manifest.parent().map(Path::to_path_buf)
+}
+
+/// Snapshot the workspace root manifest and every member manifest
+pub fn read_manifests(project_path: &Path) -> BTreeMap<PathBuf, String> {
+    let metadata = create_cargo_command(
+        &["metadata", "--no-deps", "--format-version", "1"],
+        None,
+        None,
+        &CargoOptions::default(),
+    )
+    .current_dir(project_path)
+    .output()
+    .ok()
+    .and_then(|output| serde_json::from_slice::<Value>(&output.stdout).ok())
+    .unwrap_or_default();
+
+    let root = metadata["workspace_root"]
+        .as_str()
+        .map(|root| Path::new(root).join("Cargo.toml"));
+    let members = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|package| package["manifest_path"].as_str().map(PathBuf::from));
+
+    root.into_iter()
+        .chain(members)
+        .filter_map(|path| Some((path.clone(), std::fs::read_to_string(&path).ok()?)))
+        .collect()
+}
+
+/// Format a byte count for display, e.g. "1.5 MiB"
+pub fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
 }
 
 /// Format a command for display
@@ -105,3 +451,17 @@ fn shell_escape(arg: &str) -> String {
         arg.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_escape_sequences() {
+        // Colors, a hyperlink and a character set designation as term and rustfmt print them
+        let text = "\u{1b}[1m\u{1b}[32mDiff\u{1b}(B\u{1b}[m in \
+                    \u{1b}]8;;file:///src/main.rs\u{7}src/main.rs\u{1b}]8;;\u{1b}\\:\n";
+        assert_eq!(strip_ansi(text), "Diff in src/main.rs:\n");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+}
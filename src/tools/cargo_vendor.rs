@@ -0,0 +1,161 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, format_cargo_output, human_size, run_cargo_command,
+};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path};
+
+/// Vendor all dependencies into a local directory with cargo vendor, for offline builds
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_vendor")]
+pub struct CargoVendor {
+    /// Optional directory to vendor into, relative to the project (defaults to 'vendor')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub directory: Option<String>,
+
+    /// Additional Cargo.toml manifests to sync and vendor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub sync: Option<Vec<String>>,
+
+    /// Always include the version in vendored directory names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub versioned_dirs: Option<bool>,
+
+    /// Don't delete the existing vendor directory before vendoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub no_delete: Option<bool>,
+
+    /// Respect [source] configuration in .cargo/config.toml
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub respect_source_config: Option<bool>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoVendor {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Vendor all dependencies into ./vendor",
+                item: Self::default(),
+            },
+            Example {
+                description: "Vendor into a custom directory with versioned names",
+                item: Self {
+                    directory: Some("third-party".into()),
+                    versioned_dirs: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Vendor dependencies of an additional manifest as well",
+                item: Self {
+                    sync: Some(vec!["tools/Cargo.toml".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoVendor {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        let directory = self.directory.as_deref().unwrap_or("vendor");
+
+        let mut args = vec!["vendor"];
+
+        for manifest in self.sync.iter().flatten() {
+            args.extend_from_slice(&["--sync", manifest]);
+        }
+
+        if self.versioned_dirs.unwrap_or(false) {
+            args.push("--versioned-dirs");
+        }
+
+        if self.no_delete.unwrap_or(false) {
+            args.push("--no-delete");
+        }
+
+        if self.respect_source_config.unwrap_or(false) {
+            args.push("--respect-source-config");
+        }
+
+        args.push(directory);
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo vendor");
+
+        if output.status.success() {
+            let vendor_path = project_path.join(directory);
+            let (files, bytes) = dir_size(&vendor_path);
+            result.push_str(&format!(
+                "📦 Vendored into {}: {files} files, {}\n",
+                vendor_path.display(),
+                human_size(bytes)
+            ));
+            result.push_str(
+                "📝 Add the configuration shown in STDOUT to .cargo/config.toml \
+                 to build from the vendored sources\n",
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+/// Count files and total bytes under a directory
+fn dir_size(path: &Path) -> (u64, u64) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return (0, 0);
+    };
+
+    entries
+        .flatten()
+        .fold((0, 0), |(files, bytes), entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => {
+                let (sub_files, sub_bytes) = dir_size(&entry.path());
+                (files + sub_files, bytes + sub_bytes)
+            }
+            Ok(metadata) => (files + 1, bytes + metadata.len()),
+            Err(_) => (files, bytes),
+        })
+}
@@ -0,0 +1,166 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, format_cargo_output, run_cargo_command};
+use crate::watch::ProjectWatch;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Which cargo command to re-run when files change
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchCommand {
+    #[default]
+    Check,
+    Test,
+    Clippy,
+}
+
+/// Watch the project's source files and re-run cargo check, test or clippy on every change
+///
+/// Runs in the background; poll the latest result with cargo_watch_status and stop with
+/// cargo_watch_stop. Only one watch is active at a time, so starting a new one replaces it.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_watch_start")]
+pub struct CargoWatchStart {
+    /// Which command to re-run: 'check' (the default), 'test' or 'clippy'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<WatchCommand>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    /// Build all targets, including tests, benches and examples (check and clippy only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all_targets: Option<bool>,
+
+    /// How long to wait for changes to settle before re-running, in milliseconds
+    /// (defaults to 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub debounce_ms: Option<u64>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoWatchStart {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Re-run cargo check whenever a file changes",
+                item: Self::default(),
+            },
+            Example {
+                description: "Re-run the tests of one package on changes",
+                item: Self {
+                    command: Some(WatchCommand::Test),
+                    package: Some("my-lib".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoWatchStart {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let project_path = self.cargo_options.project_path(state)?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        let command = self.command.unwrap_or_default();
+        let subcommand = match command {
+            WatchCommand::Check => "check",
+            WatchCommand::Test => "test",
+            WatchCommand::Clippy => "clippy",
+        };
+
+        let mut args = vec![subcommand.to_string()];
+
+        if let Some(package) = self.package {
+            args.extend(["--package".into(), package]);
+        }
+
+        if let Some(features) = features {
+            args.extend(["--features".into(), features]);
+        }
+
+        if self.all_targets.unwrap_or(false) && !matches!(command, WatchCommand::Test) {
+            args.push("--all-targets".into());
+        }
+
+        let description = format!("cargo {}", args.join(" "));
+        let command_name = format!("cargo {subcommand}");
+        let job_path = project_path.clone();
+        let env = self.cargo_env;
+        let job = move || {
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            let cmd =
+                create_cargo_command(&args, toolchain.as_deref(), env.as_ref(), &cargo_options);
+            match run_cargo_command(cmd, &job_path) {
+                Ok(output) => (
+                    output.status.success(),
+                    format_cargo_output(&output, &job_path, &command_name),
+                ),
+                Err(e) => (false, format!("❌ Failed to run {command_name}: {e}\n")),
+            }
+        };
+
+        let debounce = Duration::from_millis(self.debounce_ms.unwrap_or(500));
+        let watch = ProjectWatch::start(project_path.clone(), description.clone(), debounce, job)?;
+
+        let mut result = String::from("=== cargo watch ===\n");
+        if let Some(previous) = state.replace_watch(Some(watch)) {
+            result.push_str(&format!(
+                "⏹️  Stopped previous watch: {} in {}\n",
+                previous.description(),
+                previous.project_path().display()
+            ));
+        }
+        result.push_str(&format!(
+            "👀 Watching {} and re-running: {description}\n",
+            project_path.display()
+        ));
+        result.push_str("ℹ️  The first run has started. Use cargo_watch_status to see results.\n");
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::state::CargoTools;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Show the latest result of the watch started with cargo_watch_start
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_watch_status")]
+pub struct CargoWatchStatus {
+    /// Optionally wait up to this many seconds for a run in progress to finish
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub wait_secs: Option<u64>,
+}
+
+impl WithExamples for CargoWatchStatus {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show the latest watch result",
+                item: Self::default(),
+            },
+            Example {
+                description: "Wait up to a minute for the current run to finish",
+                item: Self {
+                    wait_secs: Some(60),
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoWatchStatus {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let Some(watch) = state.watch() else {
+            return Ok("ℹ️  No watch is running. Start one with cargo_watch_start.\n".into());
+        };
+
+        let deadline = Instant::now() + Duration::from_secs(self.wait_secs.unwrap_or(0));
+        let mut status = watch.status();
+        while (status.running || status.runs == 0) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(100));
+            status = watch.status();
+        }
+
+        let mut result = String::from("=== cargo watch status ===\n");
+        result.push_str(&format!(
+            "👀 Watching {} (started {})\n",
+            watch.project_path().display(),
+            ago(watch.started())
+        ));
+        result.push_str(&format!("🔧 Re-running: {}\n", watch.description()));
+        result.push_str(&format!("🔁 Completed runs: {}\n", status.runs));
+        if status.running {
+            result.push_str("⏳ A run is in progress\n");
+        }
+
+        let Some(output) = status.last_output else {
+            result.push_str("\nℹ️  The first run hasn't finished yet\n");
+            return Ok(result);
+        };
+
+        if let Some(finished) = status.last_finished {
+            let outcome = if status.last_success == Some(true) {
+                "✅ succeeded"
+            } else {
+                "❌ failed"
+            };
+            result.push_str(&format!("🕒 Last run {outcome} {}\n", ago(finished)));
+        }
+
+        if !status.changed_paths.is_empty() {
+            result.push_str("📝 Triggered by changes to:\n");
+            for path in status.changed_paths.iter().take(10) {
+                let path = path.strip_prefix(watch.project_path()).unwrap_or(path);
+                result.push_str(&format!("  {}\n", path.display()));
+            }
+            if status.changed_paths.len() > 10 {
+                result.push_str(&format!(
+                    "  ... and {} more\n",
+                    status.changed_paths.len() - 10
+                ));
+            }
+        }
+
+        result.push('\n');
+        result.push_str(&output);
+        Ok(result)
+    }
+}
+
+fn ago(time: SystemTime) -> String {
+    let secs = time.elapsed().unwrap_or_default().as_secs();
+    if secs < 60 {
+        format!("{secs}s ago")
+    } else {
+        format!("{}m {}s ago", secs / 60, secs % 60)
+    }
+}
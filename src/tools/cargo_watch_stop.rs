@@ -0,0 +1,44 @@
+use crate::state::CargoTools;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Stop the watch started with cargo_watch_start
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_watch_stop")]
+pub struct CargoWatchStop {}
+
+impl WithExamples for CargoWatchStop {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Stop watching for changes",
+            item: Self {},
+        }]
+    }
+}
+
+impl Tool<CargoTools> for CargoWatchStop {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let Some(watch) = state.replace_watch(None) else {
+            return Ok("ℹ️  No watch is running\n".into());
+        };
+
+        let status = watch.status();
+        let mut result = format!(
+            "⏹️  Stopped watching {} ({})\n",
+            watch.project_path().display(),
+            watch.description()
+        );
+        result.push_str(&format!("🔁 Completed runs: {}\n", status.runs));
+        match status.last_success {
+            Some(true) => result.push_str("✅ The last run succeeded\n"),
+            Some(false) => result.push_str("❌ The last run failed\n"),
+            None => {}
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{create_cargo_command, execute_cargo_command};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Yank a published version from the registry, or undo a yank
+///
+/// Requires confirm: true, and the server must be started with
+/// CARGO_MCP_ALLOW_REGISTRY_MUTATIONS=true.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_yank")]
+pub struct CargoYank {
+    /// The version to yank (e.g., '1.2.3')
+    #[arg(long)]
+    pub version: String,
+
+    /// Optional crate name (defaults to the package in the current directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub crate_name: Option<String>,
+
+    /// Undo a previous yank, making the version available to new lockfiles again
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub undo: Option<bool>,
+
+    /// Confirm the change. Yanked versions stop being selected for new lockfiles,
+    /// which breaks anyone depending on exactly that version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub confirm: Option<bool>,
+
+    /// Optional registry to use (defaults to crates.io)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub registry: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoYank {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Yank a broken release of the current package",
+                item: Self {
+                    version: "1.2.3".into(),
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Undo a yank for a specific crate",
+                item: Self {
+                    version: "0.4.0".into(),
+                    crate_name: Some("my-crate".into()),
+                    undo: Some(true),
+                    confirm: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoYank {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        if !state.allow_registry_mutations() {
+            return Err(anyhow!(
+                "Registry mutations are disabled on this server. Restart it with \
+                 CARGO_MCP_ALLOW_REGISTRY_MUTATIONS=true to allow cargo_yank."
+            ));
+        }
+
+        let undo = self.undo.unwrap_or(false);
+        if !self.confirm.unwrap_or(false) {
+            let action = if undo { "Un-yanking" } else { "Yanking" };
+            return Err(anyhow!(
+                "{action} version {} changes the registry and affects every user of the \
+                 crate. Call again with confirm: true to proceed.",
+                self.version
+            ));
+        }
+
+        // Yanking a named crate doesn't need a local project
+        let project_path = if self.crate_name.is_some() {
+            match state.get_context(None)? {
+                Some(context) => context,
+                None => std::env::current_dir()?,
+            }
+        } else {
+            self.cargo_options.project_path(state)?
+        };
+
+        let mut args = vec!["yank", "--version", &self.version];
+
+        if undo {
+            args.push("--undo");
+        }
+
+        if let Some(ref registry) = self.registry {
+            args.extend_from_slice(&["--registry", registry]);
+        }
+
+        if let Some(ref crate_name) = self.crate_name {
+            args.push(crate_name);
+        }
+
+        let command_name = if undo {
+            "cargo yank --undo"
+        } else {
+            "cargo yank"
+        };
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+        let cmd = create_cargo_command(&args, None, self.cargo_env.as_ref(), &cargo_options);
+        execute_cargo_command(cmd, &project_path, command_name)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::CargoOptions;
+use crate::tools::cargo_utils::{
+    create_cargo_command, ensure_cargo_subcommand, format_cargo_output, run_cargo_command,
+};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Cross-compile with zig as the linker using cargo zigbuild (requires cargo-zigbuild and zig)
+///
+/// For Linux gnu targets, glibc_version links against an older glibc so the binary runs on
+/// older distributions. The target must be installed with rustup.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "cargo_zigbuild")]
+pub struct CargoZigbuild {
+    /// Target triple to build for (e.g., 'x86_64-unknown-linux-gnu', 'aarch64-unknown-linux-musl')
+    #[arg(long)]
+    pub target: String,
+
+    /// Optional glibc version to target for gnu targets (e.g., '2.17')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub glibc_version: Option<String>,
+
+    /// Optional package name (for workspaces)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub package: Option<String>,
+
+    /// Optional binary target to build
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Build in release mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Space-separated list of features to activate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub cargo_options: CargoOptions,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+
+    /// Optional environment variables to set for the cargo command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(skip)]
+    pub cargo_env: Option<HashMap<String, String>>,
+}
+
+impl WithExamples for CargoZigbuild {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Build a release binary that runs on glibc 2.17 and newer",
+                item: Self {
+                    target: "x86_64-unknown-linux-gnu".into(),
+                    glibc_version: Some("2.17".into()),
+                    release: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build a static musl binary for 64-bit ARM",
+                item: Self {
+                    target: "aarch64-unknown-linux-musl".into(),
+                    release: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CargoZigbuild {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let target = match self.glibc_version {
+            Some(ref glibc) if self.target.ends_with("-gnu") => format!("{}.{glibc}", self.target),
+            Some(_) => {
+                return Err(anyhow!(
+                    "glibc_version only applies to gnu targets, not {}",
+                    self.target
+                ));
+            }
+            None => self.target.clone(),
+        };
+
+        let project_path = self.cargo_options.project_path(state)?;
+        ensure_cargo_subcommand("zigbuild", "cargo-zigbuild")?;
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        // Use global cargo options from args, or the session defaults
+        let cargo_options = self.cargo_options.with_session_defaults(state)?;
+
+        // Use features from args, session default, or none
+        let features = self
+            .features
+            .or_else(|| state.get_default_features(None).unwrap_or(None));
+
+        // Without release given, build with the session's default profile, if any
+        let default_profile = match self.release {
+            Some(_) => None,
+            None => state.get_default_profile(None)?,
+        };
+
+        let release = self.release.unwrap_or(false);
+        let mut args = vec!["zigbuild", "--target", &target];
+
+        if let Some(ref package) = self.package {
+            args.extend_from_slice(&["--package", package]);
+        }
+
+        if let Some(ref bin) = self.bin {
+            args.extend_from_slice(&["--bin", bin]);
+        }
+
+        if release {
+            args.push("--release");
+        }
+
+        if let Some(ref profile) = default_profile {
+            args.extend_from_slice(&["--profile", profile]);
+        }
+
+        if let Some(ref features) = features {
+            args.extend_from_slice(&["--features", features]);
+        }
+
+        let cmd = create_cargo_command(
+            &args,
+            toolchain.as_deref(),
+            self.cargo_env.as_ref(),
+            &cargo_options,
+        );
+        let output = run_cargo_command(cmd, &project_path)?;
+        let mut result = format_cargo_output(&output, &project_path, "cargo zigbuild");
+
+        if output.status.success() {
+            // Artifacts go to the directory of the plain triple, without the glibc suffix
+            let profile = match default_profile.as_deref() {
+                Some("dev") => "debug",
+                Some(profile) => profile,
+                None if release => "release",
+                None => "debug",
+            };
+            result.push_str(&format!(
+                "📦 Artifacts: {}\n",
+                cargo_options
+                    .global
+                    .target_path(&project_path)
+                    .join(&self.target)
+                    .join(profile)
+                    .display()
+            ));
+        }
+
+        Ok(result)
+    }
+}
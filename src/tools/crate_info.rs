@@ -0,0 +1,162 @@
+use crate::state::CargoTools;
+use crate::tools::crates_io::{encode_query_value, ensure_online, format_downloads, get_json};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Look up a crate on crates.io: available versions, and the feature flags, MSRV and
+/// license of one version (the latest stable release by default)
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "crate_info")]
+pub struct CrateInfo {
+    /// Name of the crate (e.g., 'tokio')
+    #[arg(long)]
+    pub crate_name: String,
+
+    /// Optional version to describe (defaults to the latest stable, non-yanked version)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub version: Option<String>,
+
+    /// Maximum number of versions to list, newest first (defaults to 15)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub max_versions: Option<u32>,
+
+    /// Include yanked versions in the version list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub include_yanked: Option<bool>,
+}
+
+impl WithExamples for CrateInfo {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Show versions and features of the latest release",
+                item: Self {
+                    crate_name: "tokio".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the features and MSRV of a specific version",
+                item: Self {
+                    crate_name: "serde".into(),
+                    version: Some("1.0.200".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CrateInfo {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        ensure_online(state)?;
+
+        let response = get_json(&format!("/crates/{}", encode_query_value(&self.crate_name)))?;
+        let krate = &response["crate"];
+        let versions = response["versions"].as_array().cloned().unwrap_or_default();
+
+        let is_yanked = |v: &Value| v["yanked"].as_bool().unwrap_or(false);
+        let number = |v: &Value| v["num"].as_str().unwrap_or("?").to_string();
+
+        let selected = match self.version {
+            Some(ref wanted) => versions
+                .iter()
+                .find(|v| v["num"] == wanted.as_str())
+                .ok_or_else(|| anyhow!("{} has no version {wanted}", self.crate_name))?,
+            None => {
+                let latest = krate["max_stable_version"]
+                    .as_str()
+                    .or_else(|| krate["max_version"].as_str());
+                versions
+                    .iter()
+                    .find(|v| latest.is_some_and(|latest| v["num"] == latest))
+                    .or_else(|| versions.iter().find(|v| !is_yanked(v)))
+                    .or_else(|| versions.first())
+                    .ok_or_else(|| anyhow!("{} has no published versions", self.crate_name))?
+            }
+        };
+
+        let mut result = format!("=== crate info: {} ===\n", self.crate_name);
+        if let Some(description) = krate["description"].as_str() {
+            result.push_str(&format!("{}\n", description.trim()));
+        }
+        for (label, key) in [("🏠 Homepage", "homepage"), ("📂 Repository", "repository")] {
+            if let Some(url) = krate[key].as_str() {
+                result.push_str(&format!("{label}: {url}\n"));
+            }
+        }
+        result.push_str(&format!(
+            "⬇️  Downloads: {} ({} recent)\n\n",
+            format_downloads(krate["downloads"].as_u64().unwrap_or(0)),
+            format_downloads(krate["recent_downloads"].as_u64().unwrap_or(0))
+        ));
+
+        result.push_str(&format!("📦 Version {}", number(selected)));
+        if is_yanked(selected) {
+            result.push_str(" (yanked)");
+        }
+        result.push('\n');
+        result.push_str(&format!(
+            "  📜 License: {}\n",
+            selected["license"].as_str().unwrap_or("unspecified")
+        ));
+        result.push_str(&format!(
+            "  🦀 MSRV (rust-version): {}\n",
+            selected["rust_version"].as_str().unwrap_or("not declared")
+        ));
+        if let Some(edition) = selected["edition"].as_str() {
+            result.push_str(&format!("  📖 Edition: {edition}\n"));
+        }
+
+        match selected["features"].as_object().filter(|f| !f.is_empty()) {
+            Some(features) => {
+                result.push_str("  🚩 Features:\n");
+                for (name, enables) in features {
+                    let enables: Vec<&str> = enables
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Value::as_str)
+                        .collect();
+                    if enables.is_empty() {
+                        result.push_str(&format!("    {name}\n"));
+                    } else {
+                        result.push_str(&format!("    {name} = [{}]\n", enables.join(", ")));
+                    }
+                }
+            }
+            None => result.push_str("  🚩 Features: none\n"),
+        }
+
+        let include_yanked = self.include_yanked.unwrap_or(false);
+        let listed: Vec<&Value> = versions
+            .iter()
+            .filter(|v| include_yanked || !is_yanked(v))
+            .collect();
+        let max_versions = self.max_versions.unwrap_or(15) as usize;
+
+        result.push_str(&format!("\n🏷️  Versions ({} total):\n", listed.len()));
+        for version in listed.iter().take(max_versions) {
+            let date = version["created_at"].as_str().unwrap_or("");
+            let date = date.split('T').next().unwrap_or(date);
+            let yanked = if is_yanked(version) { " (yanked)" } else { "" };
+            result.push_str(&format!("  {} {date}{yanked}\n", number(version)));
+        }
+        if listed.len() > max_versions {
+            result.push_str(&format!(
+                "  ... and {} older\n",
+                listed.len() - max_versions
+            ));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::state::CargoTools;
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::{
+    process::Command,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+const API_URL: &str = "https://crates.io/api/v1";
+
+/// crates.io asks API clients to make at most one request per second
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Fail if the session is set to be offline (see set_cargo_defaults), so that crates.io
+/// isn't queried against the user's wishes
+pub fn ensure_online(state: &mut CargoTools) -> Result<()> {
+    if state.get_default_cargo_options(None)?.is_offline() {
+        return Err(anyhow!(
+            "crates.io can't be queried while the session is offline; turn off offline and \
+             frozen with set_cargo_defaults to search or look up crates"
+        ));
+    }
+    Ok(())
+}
+
+/// Fetch a crates.io API endpoint (e.g., `/crates/serde`) and parse the JSON response
+///
+/// Requests go through curl, and are spaced out to respect the crates.io rate limit.
+pub fn get_json(path: &str) -> Result<Value> {
+    wait_for_rate_limit();
+
+    let url = format!("{API_URL}{path}");
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--user-agent", &user_agent()])
+        .arg(&url)
+        .output()
+        .map_err(|e| anyhow!("Failed to run curl, which is needed to query crates.io: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("404") {
+            return Err(anyhow!("Not found on crates.io: {url}"));
+        }
+        if stderr.contains("429") {
+            return Err(anyhow!(
+                "crates.io is rate limiting requests, try again later"
+            ));
+        }
+        return Err(anyhow!("Request to {url} failed: {}", stderr.trim()));
+    }
+
+    serde_json::from_slice(&output.stdout)
+        .map_err(|e| anyhow!("Unexpected response from crates.io: {e}"))
+}
+
+/// Percent-encode a value for use in a query string
+pub fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Format a download count compactly, e.g. 1234567 as "1.2M"
+pub fn format_downloads(downloads: u64) -> String {
+    match downloads {
+        0..1_000 => downloads.to_string(),
+        // From 999_950 on, one decimal of K rounds to 1000.0K
+        1_000..999_950 => format!("{:.1}K", downloads as f64 / 1_000.0),
+        _ => format!("{:.1}M", downloads as f64 / 1_000_000.0),
+    }
+}
+
+/// crates.io requires a user agent that identifies the client
+fn user_agent() -> String {
+    format!(
+        "cargo-mcp/{} ({})",
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_REPOSITORY")
+    )
+}
+
+fn wait_for_rate_limit() {
+    let mut last_request = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(last) = *last_request {
+        let elapsed = last.elapsed();
+        if elapsed < MIN_REQUEST_INTERVAL {
+            thread::sleep(MIN_REQUEST_INTERVAL - elapsed);
+        }
+    }
+    *last_request = Some(Instant::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_downloads() {
+        assert_eq!(format_downloads(999), "999");
+        assert_eq!(format_downloads(1_000), "1.0K");
+        assert_eq!(format_downloads(999_949), "999.9K");
+        assert_eq!(format_downloads(999_950), "1.0M");
+        assert_eq!(format_downloads(1_234_567), "1.2M");
+    }
+}
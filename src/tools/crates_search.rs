@@ -0,0 +1,122 @@
+use crate::state::CargoTools;
+use crate::tools::crates_io::{encode_query_value, ensure_online, format_downloads, get_json};
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// How to order search results
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSort {
+    #[default]
+    Relevance,
+    Downloads,
+    RecentDownloads,
+    RecentUpdates,
+    New,
+}
+
+/// Search crates.io for crates matching a query
+///
+/// Returns each crate's name, description, latest version and download counts.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "crates_search")]
+pub struct CratesSearch {
+    /// Search terms (e.g., 'async http client')
+    #[arg(long)]
+    pub query: String,
+
+    /// Optional ordering: 'relevance' (the default), 'downloads', 'recent_downloads',
+    /// 'recent_updates' or 'new'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub sort: Option<SearchSort>,
+
+    /// Maximum number of results (defaults to 10, at most 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub limit: Option<u32>,
+}
+
+impl WithExamples for CratesSearch {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Find crates for parsing command line arguments",
+                item: Self {
+                    query: "command line argument parser".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Find the most downloaded async runtimes",
+                item: Self {
+                    query: "async runtime".into(),
+                    sort: Some(SearchSort::Downloads),
+                    limit: Some(5),
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for CratesSearch {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        ensure_online(state)?;
+
+        let limit = self.limit.unwrap_or(10).clamp(1, 100);
+        let sort = match self.sort.unwrap_or_default() {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Downloads => "downloads",
+            SearchSort::RecentDownloads => "recent-downloads",
+            SearchSort::RecentUpdates => "recent-updates",
+            SearchSort::New => "new",
+        };
+
+        let response = get_json(&format!(
+            "/crates?q={}&per_page={limit}&sort={sort}",
+            encode_query_value(&self.query)
+        ))?;
+
+        let crates = response["crates"].as_array().cloned().unwrap_or_default();
+        let mut result = format!("=== crates.io search: {} ===\n", self.query);
+
+        if crates.is_empty() {
+            result.push_str("ℹ️  No crates found\n");
+            return Ok(result);
+        }
+
+        if let Some(total) = response["meta"]["total"].as_u64() {
+            result.push_str(&format!(
+                "🔍 Showing {} of {total} matching crates\n\n",
+                crates.len()
+            ));
+        }
+
+        for krate in &crates {
+            let version = krate["max_stable_version"]
+                .as_str()
+                .or_else(|| krate["max_version"].as_str())
+                .unwrap_or("?");
+            result.push_str(&format!(
+                "📦 {} {version}\n",
+                krate["name"].as_str().unwrap_or("?")
+            ));
+            if let Some(description) = krate["description"].as_str() {
+                result.push_str(&format!("   {}\n", description.trim()));
+            }
+            result.push_str(&format!(
+                "   ⬇️  {} downloads ({} recent)\n",
+                format_downloads(krate["downloads"].as_u64().unwrap_or(0)),
+                format_downloads(krate["recent_downloads"].as_u64().unwrap_or(0))
+            ));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,554 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::{CargoOutput, format_cargo_output, run_cargo_command, strip_ansi};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Message format that makes cargo report compiler diagnostics as JSON on stdout
+pub const MESSAGE_FORMAT: &str = "json-diagnostic-rendered-ansi";
+
+/// Like `MESSAGE_FORMAT`, with diagnostics rendered as one line each
+pub const MESSAGE_FORMAT_SHORT: &str = "json-diagnostic-short,json-diagnostic-rendered-ansi";
+
+/// Options of the tools that report compiler diagnostics
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+pub struct DiagnosticOptions {
+    /// Only return errors, leaving out warnings and notes from the compiler and cargo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub errors_only: Option<bool>,
+
+    /// Instead of listing every diagnostic, count them per lint or error code and per file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub summarize_diagnostics: Option<bool>,
+
+    /// Only return the diagnostics that are new since the last run of this tool in the
+    /// project, and the ones that have been fixed (instead of a list or summary)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub diff: Option<bool>,
+}
+
+/// A compiler diagnostic (error, warning, ...), from cargo's JSON messages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// "error", "warning", "note", "help" or "failure-note"
+    pub level: String,
+    /// Error code or lint name, e.g. "E0308" or "unused_variables"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub message: String,
+    /// Location of the primary span
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_start: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_end: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_start: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_end: Option<u64>,
+    /// The diagnostic as rustc prints it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        self.level.starts_with("error")
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.level == "warning"
+    }
+
+    /// Whether two diagnostics are about the same problem. Lines are left out, since
+    /// editing a file moves the diagnostics after the edit.
+    fn same_problem(&self, other: &Self) -> bool {
+        self.level == other.level
+            && self.code == other.code
+            && self.message == other.message
+            && self.file == other.file
+    }
+
+    /// One line, e.g. "warning[unused_variables]: unused variable: `x` (src/main.rs:2)"
+    fn one_line(&self) -> String {
+        let mut line = self.level.clone();
+        if let Some(ref code) = self.code {
+            line.push_str(&format!("[{code}]"));
+        }
+        line.push_str(&format!(": {}", self.message));
+        match (&self.file, self.line_start) {
+            (Some(file), Some(line_start)) => line.push_str(&format!(" ({file}:{line_start})")),
+            (Some(file), None) => line.push_str(&format!(" ({file})")),
+            _ => {}
+        }
+        line
+    }
+}
+
+/// How the diagnostics of a run compare with the last run
+#[derive(Debug, Default)]
+pub enum Comparison {
+    /// No comparison was asked for
+    #[default]
+    None,
+    /// There's no earlier run to compare with
+    NoEarlierRun,
+    /// Diagnostics that weren't there in the last run, and ones that are gone
+    Changes {
+        new: Vec<Diagnostic>,
+        fixed: Vec<Diagnostic>,
+    },
+}
+
+/// The diagnostics of a cargo run
+#[derive(Debug, Default, Serialize)]
+pub struct DiagnosticReport {
+    pub errors: usize,
+    pub warnings: usize,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Number of diagnostics left out by `hide_warnings`
+    #[serde(skip)]
+    pub hidden: usize,
+    #[serde(skip)]
+    pub comparison: Comparison,
+}
+
+impl DiagnosticOptions {
+    /// Take the diagnostics out of a run's output (see `DiagnosticReport::extract`) and
+    /// record them for the next comparison, comparing them with the last run with `diff`
+    pub fn take(
+        &self,
+        state: &mut CargoTools,
+        output: &mut CargoOutput,
+        project_path: &Path,
+        command_name: &str,
+        keep_color: bool,
+    ) -> Result<DiagnosticReport> {
+        let mut report = DiagnosticReport::extract(output, keep_color);
+
+        // The rendered text isn't needed to compare, and would only grow the session
+        let recorded = report
+            .diagnostics
+            .iter()
+            .map(|diagnostic| Diagnostic {
+                rendered: None,
+                ..diagnostic.clone()
+            })
+            .collect();
+        let last = state.get_last_diagnostics(project_path, command_name, None)?;
+        state.set_last_diagnostics(project_path.to_path_buf(), command_name, recorded, None)?;
+        if self.diff.unwrap_or(false) {
+            report.comparison = match last {
+                Some(last) => report.compare(last),
+                None => Comparison::NoEarlierRun,
+            };
+        }
+
+        if self.errors_only.unwrap_or(false) {
+            report.hide_warnings(output);
+        }
+        Ok(report)
+    }
+
+    /// Format a report as asked for with these options
+    pub fn format(&self, report: &DiagnosticReport) -> String {
+        report.format(self.summarize_diagnostics.unwrap_or(false))
+    }
+}
+
+impl DiagnosticReport {
+    /// Take cargo's JSON messages out of the captured stdout, leaving anything else (e.g.,
+    /// test output) in place. Escape sequences are stripped from the rendered diagnostics
+    /// unless `keep_color` is set.
+    pub fn extract(output: &mut CargoOutput, keep_color: bool) -> Self {
+        let mut report = Self::default();
+        let mut rest = String::new();
+
+        for line in output.stdout.lines() {
+            let message = line
+                .starts_with('{')
+                .then(|| serde_json::from_str::<Value>(line).ok())
+                .flatten()
+                .filter(|message| message["reason"].is_string());
+            let Some(message) = message else {
+                rest.push_str(line);
+                rest.push('\n');
+                continue;
+            };
+
+            if message["reason"] == "compiler-message"
+                && let Some(mut diagnostic) = parse_diagnostic(&message["message"])
+            {
+                if !keep_color {
+                    diagnostic.rendered = diagnostic.rendered.as_deref().map(strip_ansi);
+                }
+                // The same diagnostic shows up once per target built from the same source
+                if !report.diagnostics.contains(&diagnostic) {
+                    report.diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        report.errors = report.diagnostics.iter().filter(|d| d.is_error()).count();
+        report.warnings = report.diagnostics.iter().filter(|d| d.is_warning()).count();
+        output.stdout = rest;
+        report
+    }
+
+    /// Find the diagnostics that are new compared with the last run, and the ones fixed
+    fn compare(&self, mut last: Vec<Diagnostic>) -> Comparison {
+        let mut new = Vec::new();
+        for diagnostic in &self.diagnostics {
+            match last.iter().position(|old| old.same_problem(diagnostic)) {
+                Some(index) => {
+                    last.remove(index);
+                }
+                None => new.push(diagnostic.clone()),
+            }
+        }
+        Comparison::Changes { new, fixed: last }
+    }
+
+    /// Leave out everything but errors, here and in cargo's own messages on stderr
+    pub fn hide_warnings(&mut self, output: &mut CargoOutput) {
+        let count = self.diagnostics.len();
+        self.diagnostics.retain(Diagnostic::is_error);
+        self.hidden += count - self.diagnostics.len();
+        if let Comparison::Changes { new, fixed } = &mut self.comparison {
+            new.retain(Diagnostic::is_error);
+            fixed.retain(Diagnostic::is_error);
+        }
+
+        let mut stderr = String::new();
+        for line in output.stderr.lines() {
+            if !line.starts_with("warning:") && !line.starts_with("note:") {
+                stderr.push_str(line);
+                stderr.push('\n');
+            }
+        }
+        output.stderr = stderr;
+    }
+
+    /// A summary line followed by the diagnostics as JSON, or by their counts per code and
+    /// per file with `summarize`, or by the changes since the last run if they were
+    /// compared. Nothing without diagnostics.
+    pub fn format(&self, summarize: bool) -> String {
+        let fixed = match self.comparison {
+            Comparison::Changes { ref fixed, .. } => fixed.len(),
+            _ => 0,
+        };
+        if self.diagnostics.is_empty() && self.hidden == 0 && fixed == 0 {
+            return String::new();
+        }
+
+        let mut result = format!(
+            "🩺 Diagnostics: {}, {}",
+            plural(self.errors, "error"),
+            plural(self.warnings, "warning")
+        );
+        if self.hidden > 0 {
+            result.push_str(&format!(
+                " (only errors are shown, {} left out)",
+                self.hidden
+            ));
+        }
+        result.push('\n');
+
+        match self.comparison {
+            Comparison::None => {}
+            Comparison::NoEarlierRun => {
+                result.push_str("ℹ️  No earlier run to compare with, so all are listed\n");
+            }
+            Comparison::Changes { ref new, ref fixed } => {
+                result.push_str(&self.format_changes(new, fixed));
+                return result;
+            }
+        }
+
+        if self.diagnostics.is_empty() {
+            return result;
+        }
+        if summarize {
+            result.push_str(&self.counts());
+        } else {
+            result.push_str(&serde_json::to_string_pretty(&self.diagnostics).unwrap_or_default());
+            result.push('\n');
+        }
+        result
+    }
+
+    /// The new diagnostics as JSON, and the fixed ones as one line each
+    fn format_changes(&self, new: &[Diagnostic], fixed: &[Diagnostic]) -> String {
+        if new.is_empty() && fixed.is_empty() {
+            return "🟰 Same diagnostics as in the last run\n".to_string();
+        }
+
+        let mut result = format!(
+            "Since the last run: {} new, {} fixed\n",
+            new.len(),
+            fixed.len()
+        );
+        if !new.is_empty() {
+            result.push_str("🆕 New:\n");
+            result.push_str(&serde_json::to_string_pretty(new).unwrap_or_default());
+            result.push('\n');
+        }
+        if !fixed.is_empty() {
+            result.push_str("🎉 Fixed:\n");
+            for diagnostic in fixed {
+                result.push_str(&format!("  {}\n", diagnostic.one_line()));
+            }
+        }
+        result
+    }
+
+    /// Count the diagnostics per lint or error code, e.g. "unused_variables: 14 across 6
+    /// files", and the errors and warnings per file, most frequent first
+    fn counts(&self) -> String {
+        let mut by_code: BTreeMap<&str, (usize, BTreeSet<&str>)> = BTreeMap::new();
+        let mut by_file: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+        for diagnostic in &self.diagnostics {
+            let code = diagnostic.code.as_deref().unwrap_or(&diagnostic.level);
+            let (count, files) = by_code.entry(code).or_default();
+            *count += 1;
+            files.extend(diagnostic.file.as_deref());
+
+            if let Some(ref file) = diagnostic.file {
+                let (errors, warnings) = by_file.entry(file).or_default();
+                *errors += usize::from(diagnostic.is_error());
+                *warnings += usize::from(diagnostic.is_warning());
+            }
+        }
+
+        let mut by_code: Vec<_> = by_code.into_iter().collect();
+        by_code.sort_by_key(|(_, (count, _))| std::cmp::Reverse(*count));
+        let mut result = String::from("By code:\n");
+        for (code, (count, files)) in by_code {
+            let spread = match files.len() {
+                0 => String::new(),
+                1 => " in 1 file".to_string(),
+                files => format!(" across {files} files"),
+            };
+            result.push_str(&format!("  {code}: {count}{spread}\n"));
+        }
+
+        let mut by_file: Vec<_> = by_file.into_iter().collect();
+        by_file.sort_by_key(|(_, (errors, warnings))| std::cmp::Reverse(errors + warnings));
+        if !by_file.is_empty() {
+            result.push_str("By file:\n");
+        }
+        for (file, (errors, warnings)) in by_file {
+            let counts = match (errors, warnings) {
+                (0, warnings) => plural(warnings, "warning"),
+                (errors, 0) => plural(errors, "error"),
+                (errors, warnings) => {
+                    format!(
+                        "{}, {}",
+                        plural(errors, "error"),
+                        plural(warnings, "warning")
+                    )
+                }
+            };
+            result.push_str(&format!("  {file}: {counts}\n"));
+        }
+        result
+    }
+}
+
+/// Run a cargo command given one of the JSON message formats, and format its output
+/// followed by the diagnostics it reported
+pub fn execute_with_diagnostics(
+    state: &mut CargoTools,
+    cmd: Command,
+    project_path: &PathBuf,
+    command_name: &str,
+    options: &DiagnosticOptions,
+    keep_color: bool,
+) -> Result<String> {
+    let mut output = run_cargo_command(cmd, project_path)?;
+    let report = options.take(state, &mut output, project_path, command_name, keep_color)?;
+    let mut result = format_cargo_output(&output, project_path, command_name);
+    result.push_str(&options.format(&report));
+    Ok(result)
+}
+
+/// Parse rustc's JSON diagnostic format, located at its primary span
+fn parse_diagnostic(message: &Value) -> Option<Diagnostic> {
+    let spans = message["spans"].as_array();
+    let span = spans
+        .into_iter()
+        .flatten()
+        .find(|span| span["is_primary"] == true)
+        .or_else(|| spans.and_then(|spans| spans.first()));
+    let span_field = |key: &str| span.and_then(|span| span[key].as_u64());
+
+    Some(Diagnostic {
+        level: message["level"].as_str()?.to_string(),
+        code: message["code"]["code"].as_str().map(String::from),
+        message: message["message"].as_str()?.to_string(),
+        file: span.and_then(|span| span["file_name"].as_str().map(String::from)),
+        line_start: span_field("line_start"),
+        line_end: span_field("line_end"),
+        column_start: span_field("column_start"),
+        column_end: span_field("column_end"),
+        rendered: message["rendered"].as_str().map(String::from),
+    })
+}
+
+/// "1 error", "2 warnings"
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::ExitStatus;
+
+    /// cargo check --message-format json-diagnostic-rendered-ansi of a crate with an error and
+    /// two warnings, one of them reported for two targets (trimmed to the fields read here)
+    const STDOUT: &str = r#"{"reason":"compiler-message","target":{"name":"proj"},"message":{"$message_type":"diagnostic","message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":20,"byte_end":21,"line_start":2,"line_end":2,"column_start":9,"column_end":10,"is_primary":true}],"children":[],"rendered":"\u001b[1m\u001b[33mwarning\u001b[0m\u001b[1m: unused variable: `x`\u001b[0m\n \u001b[1m\u001b[94m--> \u001b[0msrc/main.rs:2:9\n"}}
+{"reason":"compiler-message","target":{"name":"proj"},"message":{"$message_type":"diagnostic","message":"mismatched types","code":{"code":"E0308","explanation":null},"level":"error","spans":[{"file_name":"src/main.rs","byte_start":38,"byte_end":41,"line_start":3,"line_end":3,"column_start":12,"column_end":15,"is_primary":false},{"file_name":"src/main.rs","byte_start":44,"byte_end":47,"line_start":3,"line_end":3,"column_start":18,"column_end":21,"is_primary":true}],"children":[],"rendered":"\u001b[1m\u001b[91merror[E0308]\u001b[0m\u001b[1m: mismatched types\u001b[0m\n \u001b[1m\u001b[94m--> \u001b[0msrc/main.rs:3:18\n"}}
+{"reason":"compiler-message","target":{"name":"proj"},"message":{"$message_type":"diagnostic","message":"For more information about this error, try `rustc --explain E0308`.","code":null,"level":"failure-note","spans":[],"children":[],"rendered":"\u001b[1mFor more information about this error, try `rustc --explain E0308`.\u001b[0m\n"}}
+{"reason":"compiler-artifact","package_id":"path+file:///tmp/proj#0.1.0","target":{"name":"proj"},"fresh":true}
+build script says hi
+{"reason":"compiler-message","target":{"name":"m"},"message":{"$message_type":"diagnostic","message":"unused variable: `a`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/m.rs","byte_start":13,"byte_end":14,"line_start":1,"line_end":1,"column_start":14,"column_end":15,"is_primary":true}],"children":[],"rendered":"\u001b[1m\u001b[33mwarning\u001b[0m\u001b[1m: unused variable: `a`\u001b[0m\n \u001b[1m\u001b[94m--> \u001b[0msrc/m.rs:1:14\n"}}
+{"reason":"compiler-message","target":{"name":"proj"},"message":{"$message_type":"diagnostic","message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":20,"byte_end":21,"line_start":2,"line_end":2,"column_start":9,"column_end":10,"is_primary":true}],"children":[],"rendered":"\u001b[1m\u001b[33mwarning\u001b[0m\u001b[1m: unused variable: `x`\u001b[0m\n \u001b[1m\u001b[94m--> \u001b[0msrc/main.rs:2:9\n"}}
+{"reason":"build-finished","success":false}
+"#;
+
+    const STDERR: &str = "warning: unused manifest key: package.colour
+    Checking proj v0.1.0 (/tmp/proj)
+note: to see what the problems were, use the option `--future-incompat-report`
+error: could not compile `proj` (bin \"proj\") due to 1 previous error; 2 warnings emitted
+";
+
+    fn output() -> CargoOutput {
+        CargoOutput {
+            command: "cargo check".into(),
+            status: ExitStatus::default(),
+            stdout: STDOUT.into(),
+            stderr: STDERR.into(),
+            timed_out: None,
+        }
+    }
+
+    #[test]
+    fn extracts_diagnostics() {
+        let mut output = output();
+        let report = DiagnosticReport::extract(&mut output, false);
+
+        assert_eq!((report.errors, report.warnings), (1, 2));
+        let levels: Vec<_> = report
+            .diagnostics
+            .iter()
+            .map(|d| d.level.as_str())
+            .collect();
+        assert_eq!(levels, ["warning", "error", "failure-note", "warning"]);
+
+        let error = &report.diagnostics[1];
+        assert_eq!(error.code.as_deref(), Some("E0308"));
+        assert_eq!(error.file.as_deref(), Some("src/main.rs"));
+        assert_eq!((error.line_start, error.column_start), (Some(3), Some(18)));
+        assert_eq!(
+            error.rendered.as_deref(),
+            Some("error[E0308]: mismatched types\n --> src/main.rs:3:18\n")
+        );
+        assert_eq!(report.diagnostics[2].file, None);
+
+        // Lines that aren't cargo's messages are kept
+        assert_eq!(output.stdout, "build script says hi\n");
+        assert_eq!(output.stderr, STDERR);
+    }
+
+    #[test]
+    fn keeps_color() {
+        let report = DiagnosticReport::extract(&mut output(), true);
+        let rendered = report.diagnostics[0]
+            .rendered
+            .as_deref()
+            .unwrap_or_default();
+        assert!(rendered.starts_with("\u{1b}[1m\u{1b}[33mwarning"));
+    }
+
+    #[test]
+    fn hides_warnings() {
+        let mut output = output();
+        let mut report = DiagnosticReport::extract(&mut output, false);
+        report.hide_warnings(&mut output);
+
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.hidden, 3);
+        assert_eq!(
+            output.stderr,
+            "    Checking proj v0.1.0 (/tmp/proj)\nerror: could not compile `proj` (bin \"proj\") \
+             due to 1 previous error; 2 warnings emitted\n"
+        );
+        assert!(report.format(false).starts_with(
+            "🩺 Diagnostics: 1 error, 2 warnings (only errors are shown, 3 left out)\n[\n"
+        ));
+    }
+
+    #[test]
+    fn counts_diagnostics() {
+        let report = DiagnosticReport::extract(&mut output(), false);
+        assert_eq!(
+            report.format(true),
+            "🩺 Diagnostics: 1 error, 2 warnings
+By code:
+  unused_variables: 2 across 2 files
+  E0308: 1 in 1 file
+  failure-note: 1
+By file:
+  src/main.rs: 1 error, 1 warning
+  src/m.rs: 1 warning
+"
+        );
+    }
+
+    #[test]
+    fn compares_with_last_run() {
+        let report = DiagnosticReport::extract(&mut output(), false);
+        assert!(matches!(
+            report.compare(report.diagnostics.clone()),
+            Comparison::Changes { new, fixed } if new.is_empty() && fixed.is_empty()
+        ));
+
+        // The warning in src/main.rs moved down a line, the one in src/m.rs is new and a
+        // dead_code warning has been fixed
+        let mut last = report.diagnostics.clone();
+        last[0].line_start = Some(5);
+        last[3] = Diagnostic {
+            code: Some("dead_code".into()),
+            message: "function `g` is never used".into(),
+            ..last[3].clone()
+        };
+        let mut report = report;
+        report.comparison = report.compare(last);
+        let Comparison::Changes { ref new, ref fixed } = report.comparison else {
+            panic!("expected changes, got {:?}", report.comparison);
+        };
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].message, "unused variable: `a`");
+        assert_eq!(fixed.len(), 1);
+
+        let formatted = report.format(false);
+        assert!(formatted.contains("Since the last run: 1 new, 1 fixed\n🆕 New:\n"));
+        assert!(formatted.ends_with(
+            "🎉 Fixed:\n  warning[dead_code]: function `g` is never used (src/m.rs:1)\n"
+        ));
+    }
+}
@@ -0,0 +1,36 @@
+use crate::state::CargoTools;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Get the next page of a tool result that was too long to return at once
+///
+/// Long results are cut into pages that end with a continuation token. Pass the token here
+/// to get the page after it.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "get_output_page")]
+pub struct GetOutputPage {
+    /// Continuation token from the end of the previous page (e.g., '3:32768')
+    #[arg(long)]
+    pub token: String,
+}
+
+impl WithExamples for GetOutputPage {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Get the second page of a long build log",
+            item: Self {
+                token: "3:32768".into(),
+            },
+        }]
+    }
+}
+
+impl Tool<CargoTools> for GetOutputPage {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        state.output_pages().page(&self.token)
+    }
+}
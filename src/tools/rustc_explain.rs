@@ -0,0 +1,94 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::{format_cargo_output, run_cargo_command};
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Show the long-form explanation of a compiler error code with rustc --explain
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustc_explain")]
+pub struct RustcExplain {
+    /// The error code to explain (e.g., 'E0502'; '0502' and 'e0502' also work)
+    #[arg(long)]
+    pub code: String,
+
+    /// Optional Rust toolchain to use (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+}
+
+impl WithExamples for RustcExplain {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Explain a borrow checker error",
+            item: Self {
+                code: "E0502".into(),
+                ..Self::default()
+            },
+        }]
+    }
+}
+
+impl Tool<CargoTools> for RustcExplain {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let code = normalize_error_code(&self.code)?;
+
+        // Explanations don't require a Rust project, but the project may pin a toolchain
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        let mut cmd = match toolchain {
+            Some(ref toolchain) => {
+                let mut cmd = Command::new("rustup");
+                cmd.args(["run", toolchain, "rustc"]);
+                cmd
+            }
+            None => Command::new("rustc"),
+        };
+        cmd.args(["--explain", &code]);
+
+        let output = run_cargo_command(cmd, &working_dir)?;
+        if !output.status.success() {
+            return Ok(format_cargo_output(
+                &output,
+                &working_dir,
+                "rustc --explain",
+            ));
+        }
+
+        Ok(format!(
+            "=== rustc --explain {code} ===\n\n{}",
+            output.stdout
+        ))
+    }
+}
+
+/// Accept the common ways of writing an error code and turn them into `E0502` form
+fn normalize_error_code(code: &str) -> Result<String> {
+    let code = code.trim();
+    let digits = code
+        .strip_prefix(['E', 'e'])
+        .unwrap_or(code)
+        .trim_start_matches('0');
+
+    match digits.parse::<u32>() {
+        Ok(number) if number < 10000 && digits.chars().all(|c| c.is_ascii_digit()) => {
+            Ok(format!("E{number:04}"))
+        }
+        _ => Err(anyhow!(
+            "'{code}' is not a compiler error code. Error codes look like 'E0502'."
+        )),
+    }
+}
@@ -0,0 +1,47 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Show the toolchain rustup uses for the current project and why (default, rust-toolchain
+/// file or override), along with this session's default toolchain if one is set
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_active_toolchain")]
+pub struct RustupActiveToolchain {}
+
+impl WithExamples for RustupActiveToolchain {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Show which toolchain the project builds with",
+            item: Self {},
+        }]
+    }
+}
+
+impl Tool<CargoTools> for RustupActiveToolchain {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        let mut cmd = Command::new("rustup");
+        cmd.args(["show", "active-toolchain"]);
+
+        let mut result = execute_cargo_command(cmd, &working_dir, "rustup show active-toolchain")?;
+
+        if let Some(toolchain) = state.get_default_toolchain(None)? {
+            result.push_str(&format!(
+                "🧰 Session default toolchain: {toolchain} (used by tools unless a call sets \
+                 its own toolchain)\n"
+            ));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Which rustup component command to run
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentCommand {
+    #[default]
+    List,
+    Add,
+    Remove,
+}
+
+/// List, add or remove rustup components (e.g., clippy, rustfmt, miri, rust-src,
+/// llvm-tools) for a toolchain
+///
+/// Without a toolchain, the session default is used, or otherwise the toolchain rustup
+/// picks for the project.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_component")]
+pub struct RustupComponent {
+    /// Which command to run: 'list' (the default), 'add' or 'remove'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<ComponentCommand>,
+
+    /// Components to add or remove (e.g., ['clippy', 'rust-src'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub components: Option<Vec<String>>,
+
+    /// List available components too, not just installed ones (list command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all: Option<bool>,
+
+    /// Optional Rust toolchain to manage (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+}
+
+impl WithExamples for RustupComponent {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List installed components",
+                item: Self::default(),
+            },
+            Example {
+                description: "Add miri and rust-src to the nightly toolchain",
+                item: Self {
+                    command: Some(ComponentCommand::Add),
+                    components: Some(vec!["miri".into(), "rust-src".into()]),
+                    toolchain: Some("nightly".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Add llvm-tools for coverage and profiling tools",
+                item: Self {
+                    command: Some(ComponentCommand::Add),
+                    components: Some(vec!["llvm-tools".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for RustupComponent {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let command = self.command.unwrap_or_default();
+        let components = self.components.unwrap_or_default();
+        if !matches!(command, ComponentCommand::List) && components.is_empty() {
+            return Err(anyhow!("Specify the components to add or remove"));
+        }
+
+        // The project decides the toolchain when neither the call nor the session does
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        let mut cmd = Command::new("rustup");
+        cmd.arg("component");
+        match command {
+            ComponentCommand::List => {
+                cmd.arg("list");
+                if !self.all.unwrap_or(false) {
+                    cmd.arg("--installed");
+                }
+            }
+            ComponentCommand::Add => {
+                cmd.arg("add");
+            }
+            ComponentCommand::Remove => {
+                cmd.arg("remove");
+            }
+        }
+
+        if let Some(ref toolchain) = toolchain {
+            cmd.args(["--toolchain", toolchain]);
+        }
+
+        cmd.args(&components);
+
+        execute_cargo_command(cmd, &working_dir, "rustup component")
+    }
+}
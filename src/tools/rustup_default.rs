@@ -0,0 +1,47 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Set rustup's default toolchain, installing it if needed
+///
+/// This changes the default for the user running the server, not just for this session.
+/// Projects with a rust-toolchain file or a rustup override keep using their own toolchain.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_default")]
+pub struct RustupDefault {
+    /// Toolchain to make the default (e.g., 'stable', 'nightly', '1.70.0')
+    #[arg(long)]
+    pub toolchain: String,
+}
+
+impl WithExamples for RustupDefault {
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example {
+            description: "Make stable the default toolchain",
+            item: Self {
+                toolchain: "stable".into(),
+            },
+        }]
+    }
+}
+
+impl Tool<CargoTools> for RustupDefault {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        // Setting the default doesn't require a Rust project
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        let mut cmd = Command::new("rustup");
+        cmd.args(["default", &self.toolchain]);
+
+        execute_cargo_command(cmd, &working_dir, "rustup default")
+    }
+}
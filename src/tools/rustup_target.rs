@@ -0,0 +1,120 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Which rustup target command to run
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetCommand {
+    #[default]
+    List,
+    Install,
+}
+
+/// List compilation targets or install the standard library for new ones (e.g.,
+/// wasm32-unknown-unknown, thumbv7em-none-eabihf) with rustup
+///
+/// Without a toolchain, the session default is used, or otherwise the toolchain rustup
+/// picks for the project.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_target")]
+pub struct RustupTarget {
+    /// Which command to run: 'list' (the default) or 'install'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub command: Option<TargetCommand>,
+
+    /// Target triples to install (e.g., ['wasm32-unknown-unknown'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub targets: Option<Vec<String>>,
+
+    /// List available targets too, not just installed ones (list command only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub all: Option<bool>,
+
+    /// Optional Rust toolchain to manage (e.g., 'stable', 'nightly', '1.70.0')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub toolchain: Option<String>,
+}
+
+impl WithExamples for RustupTarget {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List installed targets",
+                item: Self::default(),
+            },
+            Example {
+                description: "Install the standard library for WebAssembly",
+                item: Self {
+                    command: Some(TargetCommand::Install),
+                    targets: Some(vec!["wasm32-unknown-unknown".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install an embedded target for the nightly toolchain",
+                item: Self {
+                    command: Some(TargetCommand::Install),
+                    targets: Some(vec!["thumbv7em-none-eabihf".into()]),
+                    toolchain: Some("nightly".into()),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for RustupTarget {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let command = self.command.unwrap_or_default();
+        let targets = self.targets.unwrap_or_default();
+        if matches!(command, TargetCommand::Install) && targets.is_empty() {
+            return Err(anyhow!("Specify the targets to install"));
+        }
+
+        // The project decides the toolchain when neither the call nor the session does
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        // Use toolchain from args, session default, or none
+        let toolchain = self
+            .toolchain
+            .or_else(|| state.get_default_toolchain(None).unwrap_or(None));
+
+        let mut cmd = Command::new("rustup");
+        cmd.arg("target");
+        match command {
+            TargetCommand::List => {
+                cmd.arg("list");
+                if !self.all.unwrap_or(false) {
+                    cmd.arg("--installed");
+                }
+            }
+            TargetCommand::Install => {
+                cmd.arg("add");
+            }
+        }
+
+        if let Some(ref toolchain) = toolchain {
+            cmd.args(["--toolchain", toolchain]);
+        }
+
+        cmd.args(&targets);
+
+        execute_cargo_command(cmd, &working_dir, "rustup target")
+    }
+}
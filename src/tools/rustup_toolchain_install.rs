@@ -0,0 +1,116 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Install a Rust toolchain with rustup, so it can be used as the `toolchain` of other tools
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_toolchain_install")]
+pub struct RustupToolchainInstall {
+    /// Toolchain to install (e.g., 'stable', 'nightly', 'nightly-2024-06-01', '1.70.0')
+    #[arg(long)]
+    pub toolchain: String,
+
+    /// Installation profile, which decides the components installed by default (defaults to
+    /// minimal)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<ToolchainProfile>,
+
+    /// Optional additional components to install (e.g., ['clippy', 'rustfmt', 'rust-src'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub components: Option<Vec<String>>,
+
+    /// Optional additional targets to install the standard library for
+    /// (e.g., ['wasm32-unknown-unknown'])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub targets: Option<Vec<String>>,
+}
+
+/// Which set of components rustup installs with the toolchain
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolchainProfile {
+    #[default]
+    Minimal,
+    Default,
+    Complete,
+}
+
+impl ToolchainProfile {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Minimal => "minimal",
+            Self::Default => "default",
+            Self::Complete => "complete",
+        }
+    }
+}
+
+impl WithExamples for RustupToolchainInstall {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Install the nightly toolchain",
+                item: Self {
+                    toolchain: "nightly".into(),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install a specific Rust version with clippy and rustfmt",
+                item: Self {
+                    toolchain: "1.70.0".into(),
+                    components: Some(vec!["clippy".into(), "rustfmt".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Install stable with the standard library for WebAssembly",
+                item: Self {
+                    toolchain: "stable".into(),
+                    targets: Some(vec!["wasm32-unknown-unknown".into()]),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for RustupToolchainInstall {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        // Installing doesn't require a Rust project
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        let profile = self.profile.unwrap_or_default();
+        let mut cmd = Command::new("rustup");
+        cmd.args(["toolchain", "install", &self.toolchain]);
+        cmd.args(["--profile", profile.as_str(), "--no-self-update"]);
+
+        if let Some(ref components) = self.components
+            && !components.is_empty()
+        {
+            cmd.args(["--component", &components.join(",")]);
+        }
+
+        if let Some(ref targets) = self.targets
+            && !targets.is_empty()
+        {
+            cmd.args(["--target", &targets.join(",")]);
+        }
+
+        execute_cargo_command(cmd, &working_dir, "rustup toolchain install")
+    }
+}
@@ -0,0 +1,55 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_utils::execute_cargo_command;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// List the Rust toolchains installed with rustup
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "rustup_toolchain_list")]
+pub struct RustupToolchainList {
+    /// Also show where each toolchain is installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub verbose: Option<bool>,
+}
+
+impl WithExamples for RustupToolchainList {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "List installed toolchains",
+                item: Self::default(),
+            },
+            Example {
+                description: "List installed toolchains with their install paths",
+                item: Self {
+                    verbose: Some(true),
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for RustupToolchainList {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        // Toolchain overrides depend on the directory, so run in the project if there is one
+        let working_dir = match state.get_context(None)? {
+            Some(context) => context,
+            None => std::env::current_dir()?,
+        };
+
+        let mut cmd = Command::new("rustup");
+        cmd.args(["toolchain", "list"]);
+
+        if self.verbose.unwrap_or(false) {
+            cmd.arg("--verbose");
+        }
+
+        execute_cargo_command(cmd, &working_dir, "rustup toolchain list")
+    }
+}
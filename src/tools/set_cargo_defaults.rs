@@ -0,0 +1,93 @@
+use crate::state::CargoTools;
+use crate::tools::cargo_options::GlobalOptions;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Set session defaults for cargo's global options, used by every tool unless a call
+/// gives its own value
+///
+/// Options left out keep their current default. Use this to keep a whole session from
+/// touching the network (offline) or Cargo.lock (locked), e.g. in a sandbox, or to build in
+/// a target directory of its own. An empty target_dir goes back to cargo's default.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "set_cargo_defaults")]
+pub struct SetCargoDefaults {
+    #[serde(flatten)]
+    #[command(flatten)]
+    pub defaults: GlobalOptions,
+
+    /// Clear all defaults before applying the given ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub reset: Option<bool>,
+}
+
+impl WithExamples for SetCargoDefaults {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Run every cargo command of the session offline",
+                item: Self {
+                    defaults: GlobalOptions {
+                        offline: Some(true),
+                        ..GlobalOptions::default()
+                    },
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Never touch the network or Cargo.lock",
+                item: Self {
+                    defaults: GlobalOptions {
+                        frozen: Some(true),
+                        ..GlobalOptions::default()
+                    },
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build in a target directory of its own, apart from the IDE",
+                item: Self {
+                    defaults: GlobalOptions {
+                        target_dir: Some("target-mcp".into()),
+                        ..GlobalOptions::default()
+                    },
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the current defaults",
+                item: Self::default(),
+            },
+            Example {
+                description: "Go back to cargo's own defaults",
+                item: Self {
+                    reset: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for SetCargoDefaults {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let current = if self.reset.unwrap_or(false) {
+            GlobalOptions::default()
+        } else {
+            state.get_default_cargo_options(None)?
+        };
+
+        let defaults = self.defaults.or(&current);
+        state.set_default_cargo_options(defaults.clone(), None)?;
+
+        Ok(format!(
+            "✅ Default cargo options for this session: {}",
+            defaults.describe()
+        ))
+    }
+}
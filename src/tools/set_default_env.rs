@@ -0,0 +1,108 @@
+use crate::state::CargoTools;
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Set, unset or show environment variables for every cargo command of this session
+///
+/// The variables are set for all tools that run cargo. A call's own cargo_env takes
+/// precedence over them. Call without arguments to show the current variables.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "set_default_env")]
+pub struct SetDefaultEnv {
+    /// Optional variables to set, as 'KEY=VALUE' pairs (e.g., 'RUSTFLAGS=-D warnings')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub set: Option<Vec<String>>,
+
+    /// Optional names of variables to unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub unset: Option<Vec<String>>,
+
+    /// Unset all variables before setting the given ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub clear: Option<bool>,
+}
+
+impl WithExamples for SetDefaultEnv {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Deny warnings and show backtraces in every cargo command",
+                item: Self {
+                    set: Some(vec![
+                        "RUSTFLAGS=-D warnings".into(),
+                        "RUST_BACKTRACE=1".into(),
+                    ]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Stop setting RUSTFLAGS",
+                item: Self {
+                    unset: Some(vec!["RUSTFLAGS".into()]),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Show the variables of this session",
+                item: Self::default(),
+            },
+            Example {
+                description: "Unset all variables",
+                item: Self {
+                    clear: Some(true),
+                    ..Self::default()
+                },
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for SetDefaultEnv {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let mut set = Vec::new();
+        for pair in self.set.iter().flatten() {
+            match pair.split_once('=') {
+                Some((key, value)) if !key.is_empty() => set.push((key, value)),
+                _ => {
+                    return Err(anyhow!(
+                        "Environment variable '{pair}' must be given as 'KEY=VALUE'"
+                    ));
+                }
+            }
+        }
+
+        let mut env = if self.clear.unwrap_or(false) {
+            Default::default()
+        } else {
+            state.get_default_env(None)?
+        };
+        for name in self.unset.iter().flatten() {
+            env.remove(name);
+        }
+        for (key, value) in set {
+            env.insert(key.to_string(), value.to_string());
+        }
+
+        let changed = self.set.is_some() || self.unset.is_some() || self.clear.is_some();
+        if changed {
+            state.set_default_env(env.clone(), None)?;
+        }
+
+        let mut result = if env.is_empty() {
+            String::from("🌱 No environment variables are set for this session\n")
+        } else {
+            String::from("🌱 Environment variables for every cargo command of this session:\n")
+        };
+        for (key, value) in &env {
+            result.push_str(&format!("  {key}={value}\n"));
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::state::CargoTools;
+use anyhow::Result;
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Set the features that cargo tools activate by default in this session
+///
+/// Tools that take features use these unless a call gives its own (an empty string builds
+/// without them). Leave features out to clear the default.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "set_default_features")]
+pub struct SetDefaultFeatures {
+    /// Space or comma-separated list of features to activate by default (e.g., 'foo,bar')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub features: Option<String>,
+}
+
+impl WithExamples for SetDefaultFeatures {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Always build with the foo and bar features",
+                item: Self {
+                    features: Some("foo,bar".into()),
+                },
+            },
+            Example {
+                description: "Go back to building without extra features",
+                item: Self::default(),
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for SetDefaultFeatures {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let features = self.features.filter(|features| !features.trim().is_empty());
+        state.set_default_features(features.clone(), None)?;
+
+        Ok(match features {
+            Some(features) => format!("✅ Default features for this session: {features}"),
+            None => "✅ Cleared the default features for this session".to_string(),
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::state::CargoTools;
+use anyhow::{Result, anyhow};
+use mcplease::{
+    traits::{Tool, WithExamples},
+    types::Example,
+};
+use serde::{Deserialize, Serialize};
+
+/// Set the profile that build tools use by default in this session
+///
+/// Applies to cargo_build, cargo_check, cargo_clippy, cargo_run, cargo_test, cargo_rustc,
+/// cargo_cross and cargo_zigbuild unless a call gives its own release or profile. Leave
+/// both out to go back to cargo's defaults.
+#[derive(Debug, Default, Serialize, Deserialize, schemars::JsonSchema, clap::Args)]
+#[serde(rename = "set_default_profile")]
+pub struct SetDefaultProfile {
+    /// Build in release mode by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub release: Option<bool>,
+
+    /// Optional custom profile to build with by default (e.g., 'release-lto');
+    /// can't be combined with release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[arg(long)]
+    pub profile: Option<String>,
+}
+
+impl WithExamples for SetDefaultProfile {
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example {
+                description: "Switch the session to release builds",
+                item: Self {
+                    release: Some(true),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Build with a custom profile from Cargo.toml",
+                item: Self {
+                    profile: Some("release-lto".into()),
+                    ..Self::default()
+                },
+            },
+            Example {
+                description: "Go back to debug builds",
+                item: Self::default(),
+            },
+        ]
+    }
+}
+
+impl Tool<CargoTools> for SetDefaultProfile {
+    fn execute(self, state: &mut CargoTools) -> Result<String> {
+        let profile = match (self.release.unwrap_or(false), self.profile) {
+            (true, Some(_)) => {
+                return Err(anyhow!(
+                    "release and profile can't be combined; use profile: 'release' instead"
+                ));
+            }
+            (true, None) => Some("release".to_string()),
+            (false, profile) => profile.filter(|profile| !profile.trim().is_empty()),
+        };
+        state.set_default_profile(profile.clone(), None)?;
+
+        Ok(match profile {
+            Some(profile) => format!("✅ Default profile for this session: {profile}"),
+            None => "✅ Cleared the default profile; builds use cargo's defaults".to_string(),
+        })
+    }
+}
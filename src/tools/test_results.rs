@@ -0,0 +1,430 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A failed test with its captured output, which usually includes the panic message
+#[derive(Debug, Clone, Serialize)]
+pub struct TestFailure {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// The results of one test binary (unit tests, an integration test or doc tests)
+#[derive(Debug, Default, Serialize)]
+pub struct TestSuite {
+    /// What cargo said it was running, e.g. "tests/api.rs" or "Doc-tests my_lib"
+    /// (cargo doesn't say with quiet output)
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub binary: String,
+    pub passed: u64,
+    pub failed: u64,
+    pub ignored: u64,
+    pub filtered_out: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    /// Names of passed tests (not available with quiet output)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub passed_tests: Vec<String>,
+    /// Names of ignored tests (not available with quiet output)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignored_tests: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<TestFailure>,
+}
+
+/// Results of a cargo test run, parsed from libtest's human-readable output
+#[derive(Debug, Default, Serialize)]
+pub struct TestReport {
+    pub passed: u64,
+    pub failed: u64,
+    pub ignored: u64,
+    pub suites: Vec<TestSuite>,
+}
+
+impl TestReport {
+    /// Parse cargo test's captured output. Cargo announces each test binary on stderr and
+    /// the harness reports on stdout, in the same order.
+    pub fn parse(stdout: &str, stderr: &str) -> Self {
+        let mut binaries = test_binaries(stderr);
+
+        let mut report = Self::default();
+        let mut in_successes = false;
+        let mut lines = stdout.lines().peekable();
+        while let Some(line) = lines.next() {
+            if line.starts_with("running ") && (line.ends_with(" test") || line.ends_with(" tests"))
+            {
+                report.suites.push(TestSuite {
+                    binary: binaries.next().unwrap_or_default(),
+                    ..TestSuite::default()
+                });
+                in_successes = false;
+                continue;
+            }
+            let Some(suite) = report.suites.last_mut() else {
+                continue;
+            };
+
+            if let Some(counts) = line.strip_prefix("test result: ") {
+                parse_result_line(counts, suite);
+            } else if let Some(rest) = line.strip_prefix("test ") {
+                parse_test_line(rest, suite);
+            } else if line == "successes:" {
+                // Output of passing tests, shown with --show-output
+                in_successes = true;
+            } else if line == "failures:" {
+                in_successes = false;
+                // The final list of failed tests; with quiet output the only place naming them
+                while let Some(name) = lines.peek().and_then(|next| next.strip_prefix("    ")) {
+                    failure(suite, name.trim());
+                    lines.next();
+                }
+            } else if let Some(header) = line
+                .strip_prefix("---- ")
+                .and_then(|header| header.strip_suffix(" ----"))
+            {
+                // "---- tests::it_works stdout ----" followed by the captured output
+                let mut message = Vec::new();
+                while let Some(next) = lines.peek() {
+                    if next.starts_with("---- ") || *next == "failures:" || *next == "successes:" {
+                        break;
+                    }
+                    message.extend(lines.next());
+                }
+                let message = message.join("\n").trim().to_string();
+                if !in_successes && !message.is_empty() {
+                    let name = header.strip_suffix(" stdout").unwrap_or(header);
+                    failure(suite, name).message = Some(message);
+                }
+            }
+        }
+
+        for suite in &report.suites {
+            report.passed += suite.passed;
+            report.failed += suite.failed;
+            report.ignored += suite.ignored;
+        }
+        report
+    }
+
+    /// Names of all failed tests, in the order they were reported
+    pub fn failed_tests(&self) -> Vec<String> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.failures)
+            .map(|failure| failure.name.clone())
+            .collect()
+    }
+}
+
+/// The test binaries cargo announces on stderr as it runs them, e.g. "src/lib.rs" for unit
+/// tests, "tests/api.rs" or "Doc-tests my_lib"
+pub fn test_binaries(stderr: &str) -> impl Iterator<Item = String> + '_ {
+    stderr.lines().filter_map(|line| {
+        let line = line.trim();
+        if let Some(running) = line.strip_prefix("Running ") {
+            // "Running unittests src/lib.rs (target/debug/deps/my_lib-1234)"
+            let running = running.strip_prefix("unittests ").unwrap_or(running);
+            Some(running.split(" (").next().unwrap_or(running).to_string())
+        } else if line.starts_with("Doc-tests ") {
+            Some(line.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Failure counts over repeated runs of the same tests, to tell flaky tests from broken ones
+#[derive(Debug, Default)]
+pub struct RepeatTally {
+    runs: u32,
+    /// How often each test failed, with the output of its first failure, by the position of
+    /// its binary in the run and its name, since the same name can be used in several
+    /// binaries (which quiet output doesn't name)
+    failures: BTreeMap<(usize, String), TalliedTest>,
+}
+
+#[derive(Debug, Default)]
+struct TalliedTest {
+    binary: String,
+    failed: u32,
+    first_failure: Option<String>,
+}
+
+/// A test that failed in some of the repeated runs
+#[derive(Debug, Serialize)]
+pub struct RepeatedTest<'a> {
+    /// The test binary, as in `TestSuite` (not known with quiet output)
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pub binary: &'a str,
+    pub name: &'a str,
+    pub passed: u32,
+    pub failed: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_failure: Option<&'a str>,
+}
+
+/// Tests that failed in some runs (flaky) or in every run
+#[derive(Debug, Serialize)]
+pub struct RepeatSummary<'a> {
+    pub runs: u32,
+    pub flaky: Vec<RepeatedTest<'a>>,
+    pub always_failed: Vec<RepeatedTest<'a>>,
+}
+
+impl RepeatTally {
+    /// Count the failures of one more run
+    pub fn add(&mut self, report: &TestReport) {
+        self.runs += 1;
+        for (index, suite) in report.suites.iter().enumerate() {
+            for failure in &suite.failures {
+                let tallied = self
+                    .failures
+                    .entry((index, failure.name.clone()))
+                    .or_default();
+                tallied.binary.clone_from(&suite.binary);
+                tallied.failed += 1;
+                if tallied.first_failure.is_none() {
+                    tallied.first_failure.clone_from(&failure.message);
+                }
+            }
+        }
+    }
+
+    /// Names of the tests that failed at least once
+    pub fn failed_tests(&self) -> Vec<String> {
+        let names: BTreeSet<&String> = self.failures.keys().map(|(_, name)| name).collect();
+        names.into_iter().cloned().collect()
+    }
+
+    /// Split the failed tests into flaky and always failing ones. A test that didn't fail
+    /// is assumed to have passed, which holds as long as every run runs the same tests.
+    pub fn summary(&self) -> RepeatSummary<'_> {
+        let (always_failed, flaky) = self
+            .failures
+            .iter()
+            .map(|((_, name), tallied)| RepeatedTest {
+                binary: &tallied.binary,
+                name,
+                // Can't go below zero as long as every binary reports each test once
+                passed: self.runs.saturating_sub(tallied.failed),
+                failed: tallied.failed,
+                first_failure: tallied.first_failure.as_deref(),
+            })
+            .partition(|test| test.passed == 0);
+
+        RepeatSummary {
+            runs: self.runs,
+            flaky,
+            always_failed,
+        }
+    }
+}
+
+/// Find a failed test in the suite, adding it if it wasn't reported before
+fn failure<'a>(suite: &'a mut TestSuite, name: &str) -> &'a mut TestFailure {
+    let index = match suite.failures.iter().position(|f| f.name == name) {
+        Some(index) => index,
+        None => {
+            suite.failures.push(TestFailure {
+                name: name.to_string(),
+                message: None,
+            });
+            suite.failures.len() - 1
+        }
+    };
+    &mut suite.failures[index]
+}
+
+/// Parse the rest of "test tests::it_works ... ok" (also "ignored, reason" and "FAILED")
+fn parse_test_line(line: &str, suite: &mut TestSuite) {
+    let Some((name, result)) = line.rsplit_once(" ... ") else {
+        return;
+    };
+    // Tests with #[should_panic] are reported as "name - should panic"
+    let name = name.strip_suffix(" - should panic").unwrap_or(name);
+    if result == "ok" {
+        suite.passed_tests.push(name.to_string());
+    } else if result == "FAILED" {
+        failure(suite, name);
+    } else if result.starts_with("ignored") {
+        suite.ignored_tests.push(name.to_string());
+    }
+    // Anything else is a benchmark result ("bench: ...")
+}
+
+/// Parse "ok. 3 passed; 1 failed; 0 ignored; 0 measured; 2 filtered out; finished in 0.01s"
+fn parse_result_line(line: &str, suite: &mut TestSuite) {
+    let counts = line.split_once(". ").map_or(line, |(_, counts)| counts);
+    for part in counts.split("; ") {
+        if let Some(duration) = part.strip_prefix("finished in ") {
+            suite.duration_secs = duration.trim_end_matches('s').parse().ok();
+            continue;
+        }
+        let Some((count, label)) = part.split_once(' ') else {
+            continue;
+        };
+        let Ok(count) = count.parse() else {
+            continue;
+        };
+        match label {
+            "passed" => suite.passed = count,
+            "failed" => suite.failed = count,
+            "ignored" => suite.ignored = count,
+            "filtered out" => suite.filtered_out = count,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// cargo test of a crate whose unit tests and integration test both have a failing
+    /// tests::it_works
+    const STDOUT: &str = r#"
+running 3 tests
+test tests::it_works ... FAILED
+test tests::passes ... ok
+test tests::slow ... ignored
+
+failures:
+
+---- tests::it_works stdout ----
+
+thread 'tests::it_works' (1001) panicked at src/lib.rs:4:21:
+lib boom
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::it_works
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+
+running 1 test
+test tests::it_works ... FAILED
+
+failures:
+
+---- tests::it_works stdout ----
+
+thread 'tests::it_works' (1002) panicked at tests/api.rs:3:21:
+int boom
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::it_works
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+
+running 0 tests
+
+test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+"#;
+
+    const STDERR: &str = r#"   Compiling dup v0.1.0 (/tmp/dup)
+    Finished `test` profile [unoptimized + debuginfo] target(s) in 0.18s
+     Running unittests src/lib.rs (target/debug/deps/dup-1234)
+error: test failed, to rerun pass `--lib`
+     Running tests/api.rs (target/debug/deps/api-5678)
+error: test failed, to rerun pass `--test api`
+   Doc-tests dup
+error: 2 targets failed:
+    `--lib`
+    `--test api`
+"#;
+
+    #[test]
+    fn parses_every_suite() {
+        let report = TestReport::parse(STDOUT, STDERR);
+        assert_eq!((report.passed, report.failed, report.ignored), (1, 2, 1));
+
+        let binaries: Vec<_> = report.suites.iter().map(|s| s.binary.as_str()).collect();
+        assert_eq!(binaries, ["src/lib.rs", "tests/api.rs", "Doc-tests dup"]);
+
+        let lib = &report.suites[0];
+        assert_eq!(lib.passed_tests, ["tests::passes"]);
+        assert_eq!(lib.ignored_tests, ["tests::slow"]);
+        assert_eq!(lib.duration_secs, Some(0.0));
+        assert_eq!(lib.failures.len(), 1);
+        let message = lib.failures[0].message.as_deref().unwrap_or_default();
+        assert!(message.contains("lib boom"), "{message}");
+
+        let api = &report.suites[1];
+        assert_eq!(api.failures[0].name, "tests::it_works");
+        let message = api.failures[0].message.as_deref().unwrap_or_default();
+        assert!(message.contains("int boom"), "{message}");
+
+        assert_eq!(
+            report.failed_tests(),
+            ["tests::it_works", "tests::it_works"]
+        );
+    }
+
+    #[test]
+    fn parses_quiet_output() {
+        let stdout = "\nrunning 1 test\n.\ntest result: ok. 1 passed; 0 failed; 0 ignored; \
+                      0 measured; 2 filtered out; finished in 0.00s\n\n\nrunning 0 tests\n\n\
+                      test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 1 filtered \
+                      out; finished in 0.00s\n";
+        let report = TestReport::parse(stdout, "");
+        assert_eq!(report.suites.len(), 2);
+        assert_eq!(report.passed, 1);
+        assert_eq!(report.suites[0].binary, "");
+        assert_eq!(report.suites[0].filtered_out, 2);
+        assert_eq!(report.suites[1].filtered_out, 1);
+    }
+
+    #[test]
+    fn tallies_same_name_per_binary() {
+        let report = TestReport::parse(STDOUT, STDERR);
+        let mut tally = RepeatTally::default();
+        tally.add(&report);
+        tally.add(&report);
+
+        assert_eq!(tally.failed_tests(), ["tests::it_works"]);
+        let summary = tally.summary();
+        assert!(summary.flaky.is_empty());
+        let always: Vec<_> = summary
+            .always_failed
+            .iter()
+            .map(|test| (test.binary, test.name, test.passed, test.failed))
+            .collect();
+        assert_eq!(
+            always,
+            [
+                ("src/lib.rs", "tests::it_works", 0, 2),
+                ("tests/api.rs", "tests::it_works", 0, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn tells_flaky_from_always_failing() {
+        let mut tally = RepeatTally::default();
+        tally.add(&TestReport::parse(STDOUT, STDERR));
+        // The unit test passes in the second run
+        let passing = STDOUT
+            .replacen(
+                "test tests::it_works ... FAILED",
+                "test tests::it_works ... ok",
+                1,
+            )
+            .replacen("---- tests::it_works stdout ----", "", 1)
+            .replacen("failures:\n    tests::it_works\n", "", 1);
+        tally.add(&TestReport::parse(&passing, STDERR));
+
+        let summary = tally.summary();
+        assert_eq!(summary.runs, 2);
+        assert_eq!(summary.always_failed.len(), 1);
+        assert_eq!(summary.always_failed[0].binary, "tests/api.rs");
+        assert_eq!(summary.flaky.len(), 1);
+        assert_eq!(summary.flaky[0].binary, "src/lib.rs");
+        assert_eq!((summary.flaky[0].passed, summary.flaky[0].failed), (1, 1));
+    }
+}
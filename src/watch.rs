@@ -0,0 +1,152 @@
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Latest results of a watch, shared between the watch thread and the tools
+#[derive(Debug, Clone, Default)]
+pub struct WatchStatus {
+    /// Number of completed runs
+    pub runs: u64,
+    /// Whether a run is in progress right now
+    pub running: bool,
+    /// Whether the last completed run succeeded
+    pub last_success: Option<bool>,
+    /// Formatted output of the last completed run
+    pub last_output: Option<String>,
+    /// When the last run completed
+    pub last_finished: Option<SystemTime>,
+    /// Files whose changes triggered the last run (empty for the initial run)
+    pub changed_paths: Vec<PathBuf>,
+}
+
+/// A running watch that re-runs a job whenever source files in a project change
+///
+/// The job runs once when the watch starts, and again after each burst of changes has
+/// settled for the debounce interval. Dropping the watch stops it.
+pub struct ProjectWatch {
+    project_path: PathBuf,
+    description: String,
+    started: SystemTime,
+    status: Arc<Mutex<WatchStatus>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ProjectWatch {
+    /// Start watching `project_path`. The job returns whether it succeeded and its output.
+    pub fn start(
+        project_path: PathBuf,
+        description: String,
+        debounce: Duration,
+        job: impl Fn() -> (bool, String) + Send + 'static,
+    ) -> Result<Self> {
+        let (sender, receiver) = mpsc::channel::<Vec<PathBuf>>();
+        let root = project_path.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event
+                && !matches!(event.kind, EventKind::Access(_))
+            {
+                let paths: Vec<PathBuf> = event
+                    .paths
+                    .into_iter()
+                    .filter(|path| is_source_path(&root, path))
+                    .collect();
+                if !paths.is_empty() {
+                    let _ = sender.send(paths);
+                }
+            }
+        })?;
+        watcher.watch(&project_path, RecursiveMode::Recursive)?;
+
+        let status = Arc::new(Mutex::new(WatchStatus::default()));
+        let thread_status = Arc::clone(&status);
+        thread::spawn(move || {
+            let mut changed_paths = Vec::new();
+            loop {
+                run_job(&job, &thread_status, std::mem::take(&mut changed_paths));
+
+                // Wait for a change, then keep collecting until things have been quiet
+                // for the debounce interval. The channel closes when the watch is dropped.
+                match receiver.recv() {
+                    Ok(paths) => changed_paths.extend(paths),
+                    Err(_) => return,
+                }
+                loop {
+                    match receiver.recv_timeout(debounce) {
+                        Ok(paths) => changed_paths.extend(paths),
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                changed_paths.sort();
+                changed_paths.dedup();
+            }
+        });
+
+        Ok(Self {
+            project_path,
+            description,
+            started: SystemTime::now(),
+            status,
+            _watcher: watcher,
+        })
+    }
+
+    /// The watched project directory
+    pub fn project_path(&self) -> &Path {
+        &self.project_path
+    }
+
+    /// What is being re-run, e.g. "cargo check"
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// When the watch was started
+    pub fn started(&self) -> SystemTime {
+        self.started
+    }
+
+    /// A snapshot of the latest results
+    pub fn status(&self) -> WatchStatus {
+        self.status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+fn run_job(
+    job: &impl Fn() -> (bool, String),
+    status: &Mutex<WatchStatus>,
+    changed_paths: Vec<PathBuf>,
+) {
+    status.lock().unwrap_or_else(|e| e.into_inner()).running = true;
+    let (success, output) = job();
+
+    let mut status = status.lock().unwrap_or_else(|e| e.into_inner());
+    status.runs += 1;
+    status.running = false;
+    status.last_success = Some(success);
+    status.last_output = Some(output);
+    status.last_finished = Some(SystemTime::now());
+    status.changed_paths = changed_paths;
+}
+
+/// Ignore build output and VCS metadata, which change whenever the job itself runs
+fn is_source_path(root: &Path, path: &Path) -> bool {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    !relative.components().any(|component| {
+        matches!(
+            component,
+            Component::Normal(name) if name == "target" || name == ".git"
+        )
+    })
+}